@@ -27,24 +27,28 @@ mod data
     pub description: String,
   }
 
-  #[derive(Debug)]
-  pub struct Post
-  {
-    pub post_id: PostId,
-    pub author_id: UserId,
-    pub group_id: Option<GroupId>,
-    pub privacy: PostPrivacy,
-    pub title: String,
-    pub content: String,
+  mononym::project! {
+    #[derive(Debug)]
+    pub struct Post
+    {
+      pub post_id: PostId,
+      pub author_id: UserId,
+      pub group_id: Option<GroupId>,
+      pub privacy: PostPrivacy,
+      pub title: String,
+      pub content: String,
+    }
   }
 
-  #[derive(Debug)]
-  pub enum PostPrivacy
-  {
-    Public,
-    Private,
-    GroupRead,
-    GroupEdit,
+  mononym::refine! {
+    #[derive(Debug, Clone)]
+    pub enum PostPrivacy
+    {
+      Public,
+      Private,
+      GroupRead,
+      GroupEdit,
+    }
   }
 }
 
@@ -77,7 +81,10 @@ mod raw_query
 
 mod named_query
 {
-  use mononym::*;
+  use mononym::{
+    proof::logic::Decision,
+    *,
+  };
 
   use super::{
     data::*,
@@ -96,7 +103,7 @@ mod named_query
   }
 
   proof! {
-    UserIsAdmin(user_id: UserId);
+    UserIsAdmin(user_id: UserId) else UserIsNotAdmin;
   }
 
   pub fn get_user_info<UserIdVal: HasType<UserId>>(
@@ -121,14 +128,14 @@ mod named_query
 
   pub fn user_is_admin<UserIdVal: HasType<UserId>>(
     user_id: Named<UserIdVal, UserId>
-  ) -> Result<Option<UserIsAdmin<UserIdVal>>, DbError>
+  ) -> Result<Decision<UserIsAdmin<UserIdVal>>, DbError>
   {
     let is_admin = raw_query::user_is_admin(user_id.value())?;
 
     if is_admin {
-      Ok(Some(UserIsAdmin::new()))
+      Ok(Decision::Proved(UserIsAdmin::new()))
     } else {
-      Ok(None)
+      Ok(Decision::Refuted(UserIsAdmin::refute()))
     }
   }
 
@@ -153,51 +160,35 @@ mod privacy
     named_query::*,
   };
 
-  pub struct Public;
-  pub struct Private;
-  pub struct GroupRead;
-  pub struct GroupEdit;
-
-  proof! {
-    PostHasPrivacy<Privacy>(post_id: PostId);
-  }
-
-  pub enum SomePostPrivacy<PostIdVal: HasType<PostId>>
-  {
-    Public(PostHasPrivacy<Public, PostIdVal>),
-    Private(PostHasPrivacy<Private, PostIdVal>),
-    GroupRead(PostHasPrivacy<GroupRead, PostIdVal>),
-    GroupEdit(PostHasPrivacy<GroupEdit, PostIdVal>),
+  exists! {
+    ExistPostPrivacy(privacy: PostPrivacy) => PostHasPrivacy(post_id: PostId);
   }
 
-  pub fn check_post_privacy<
-    UserIdVal: HasType<UserId>,
-    PostIdVal: HasType<PostId>,
-    PostVal: HasType<Post>,
-  >(
+  pub fn get_post_privacy<PostIdVal: HasType<PostId>, PostVal: HasType<Post>>(
+    seed: impl Seed,
     post: &Named<PostVal, Post>,
     _post_has_id: &PostHasId<PostVal, PostIdVal>,
-  ) -> SomePostPrivacy<PostIdVal>
+  ) -> ExistPostPrivacy<impl HasType<PostPrivacy>, PostIdVal>
   {
-    match post.value().privacy {
-      PostPrivacy::Public => SomePostPrivacy::Public(PostHasPrivacy::new()),
-      PostPrivacy::Private => SomePostPrivacy::Private(PostHasPrivacy::new()),
-      PostPrivacy::GroupRead => {
-        SomePostPrivacy::GroupRead(PostHasPrivacy::new())
-      }
-      PostPrivacy::GroupEdit => {
-        SomePostPrivacy::GroupEdit(PostHasPrivacy::new())
-      }
-    }
+    new_exist_post_privacy(seed, post.value().privacy.clone())
   }
 }
 
 mod access_control
 {
-  use mononym::*;
+  use mononym::{
+    proof::field::FieldOf,
+    *,
+  };
 
   use super::{
-    data::*,
+    data::{
+      post_privacy::{
+        self,
+        IsVariant,
+      },
+      *,
+    },
     named_query::*,
     privacy::*,
   };
@@ -217,16 +208,19 @@ mod access_control
   }
 
   pub fn check_user_is_author<
+    'a,
     UserIdVal: HasType<UserId>,
     PostIdVal: HasType<PostId>,
     PostVal: HasType<Post>,
+    AuthorIdVal: HasType<&'a UserId>,
   >(
     user_id: &Named<UserIdVal, UserId>,
-    post: &Named<PostVal, Post>,
+    author_id: &Named<AuthorIdVal, &'a UserId>,
     _post_has_id: &PostHasId<PostVal, PostIdVal>,
+    _author_of_post: &FieldOf<post::AuthorId, AuthorIdVal, PostVal>,
   ) -> Option<UserIsAuthor<PostIdVal, UserIdVal>>
   {
-    if &post.value().author_id == user_id.value() {
+    if *author_id.value() == user_id.value() {
       Some(UserIsAuthor::new())
     } else {
       None
@@ -266,6 +260,23 @@ mod access_control
       .map(move |group_id| new_exist_post_group(seed, group_id.clone()))
   }
 
+  pub fn check_anyone_can_read_post<
+    PostIdVal: HasType<PostId>,
+    UserIdVal: HasType<UserId>,
+    PrivacyVal: HasType<PostPrivacy>,
+  >(
+    privacy: &Named<PrivacyVal, PostPrivacy>,
+    post_has_privacy: &PostHasPrivacy<PrivacyVal, PostIdVal>,
+  ) -> Option<UserCanReadPost<PostIdVal, UserIdVal>>
+  {
+    match post_privacy::refine(privacy) {
+      post_privacy::Refined::Public(is_public) => {
+        Some(anyone_can_read_public_post(post_has_privacy, &is_public))
+      }
+      _ => None,
+    }
+  }
+
   pub fn check_post_in_group<
     GroupIdVal: HasType<GroupId>,
     PostIdVal: HasType<PostId>,
@@ -283,69 +294,51 @@ mod access_control
     }
   }
 
-  pub fn author_can_edit_post<
-    UserIdVal: HasType<UserId>,
-    PostIdVal: HasType<PostId>,
-  >(
-    _user_is_author: &UserIsAuthor<PostIdVal, UserIdVal>
-  ) -> UserCanEditPost<PostIdVal, UserIdVal>
-  {
-    UserCanEditPost::new()
-  }
-
-  pub fn can_edit_also_can_read<
-    UserIdVal: HasType<UserId>,
-    PostIdVal: HasType<PostId>,
-  >(
-    _can_edit: &UserCanEditPost<PostIdVal, UserIdVal>
-  ) -> UserCanReadPost<PostIdVal, UserIdVal>
-  {
-    UserCanReadPost::new()
-  }
-
-  pub fn anyone_can_read_public_post<
-    UserIdVal: HasType<UserId>,
-    PostIdVal: HasType<PostId>,
-  >(
-    _post_is_public: &PostHasPrivacy<Public, PostIdVal>
-  ) -> UserCanReadPost<PostIdVal, UserIdVal>
-  {
-    UserCanReadPost::new()
-  }
-
-  pub fn admin_can_edit_any_post<
-    UserIdVal: HasType<UserId>,
-    PostIdVal: HasType<PostId>,
-  >(
-    _user_is_admin: &UserIsAdmin<UserIdVal>
-  ) -> UserCanEditPost<PostIdVal, UserIdVal>
-  {
-    UserCanEditPost::new()
-  }
-  pub fn group_member_can_read_post_with_group_read_privacy<
-    UserIdVal: HasType<UserId>,
-    PostIdVal: HasType<PostId>,
-    GroupIdVal: HasType<GroupId>,
-  >(
-    _user_in_group: &UserInGroup<GroupIdVal, UserIdVal>,
-    _post_in_group: &PostInGroup<GroupIdVal, PostIdVal>,
-    _post_has_group_read_privacy: &PostHasPrivacy<GroupRead, PostIdVal>,
-  ) -> UserCanReadPost<PostIdVal, UserIdVal>
-  {
-    UserCanReadPost::new()
-  }
-
-  pub fn group_member_can_edit_post_with_group_edit_privacy<
-    UserIdVal: HasType<UserId>,
-    PostIdVal: HasType<PostId>,
-    GroupIdVal: HasType<GroupId>,
-  >(
-    _user_in_group: &UserInGroup<GroupIdVal, UserIdVal>,
-    _post_in_group: &PostInGroup<GroupIdVal, PostIdVal>,
-    _post_has_group_read_privacy: &PostHasPrivacy<GroupEdit, PostIdVal>,
-  ) -> UserCanEditPost<PostIdVal, UserIdVal>
-  {
-    UserCanEditPost::new()
+  rule! {
+    author_can_edit_post(post_id: PostId, user_id: UserId):
+      UserIsAuthor(post_id, user_id)
+      => UserCanEditPost(post_id, user_id);
+
+    can_edit_also_can_read(post_id: PostId, user_id: UserId):
+      UserCanEditPost(post_id, user_id)
+      => UserCanReadPost(post_id, user_id);
+
+    anyone_can_read_public_post(
+      post_id: PostId,
+      user_id: UserId,
+      privacy: PostPrivacy,
+    ):
+      PostHasPrivacy(privacy, post_id),
+      IsVariant<post_privacy::Public>(privacy)
+      => UserCanReadPost(post_id, user_id);
+
+    admin_can_edit_any_post(post_id: PostId, user_id: UserId):
+      UserIsAdmin(user_id)
+      => UserCanEditPost(post_id, user_id);
+
+    group_member_can_read_post_with_group_read_privacy(
+      post_id: PostId,
+      user_id: UserId,
+      group_id: GroupId,
+      privacy: PostPrivacy,
+    ):
+      UserInGroup(group_id, user_id),
+      PostInGroup(group_id, post_id),
+      PostHasPrivacy(privacy, post_id),
+      IsVariant<post_privacy::GroupRead>(privacy)
+      => UserCanReadPost(post_id, user_id);
+
+    group_member_can_edit_post_with_group_edit_privacy(
+      post_id: PostId,
+      user_id: UserId,
+      group_id: GroupId,
+      privacy: PostPrivacy,
+    ):
+      UserInGroup(group_id, user_id),
+      PostInGroup(group_id, post_id),
+      PostHasPrivacy(privacy, post_id),
+      IsVariant<post_privacy::GroupEdit>(privacy)
+      => UserCanEditPost(post_id, user_id);
   }
 }
 
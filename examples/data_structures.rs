@@ -165,69 +165,49 @@ where
 
 mod min
 {
-  use core::marker::PhantomData;
-
-  use mononym::{
-    HasType,
-    Name,
-    Named,
-    Seed,
-  };
+  use mononym::*;
 
   use super::{
     size::NonEmpty,
     sort::Sorted,
   };
 
-  pub struct MinElem<ListVal, ElemVal>(PhantomData<(ListVal, ElemVal)>);
-
-  pub struct MinResult<'a, Elem, ListVal, ElemVal: HasType<&'a Elem>>
-  {
-    elem: Named<ElemVal, &'a Elem>,
-    min_proof: MinElem<ListVal, ElemVal>,
+  exists! {
+    ExistMinElem(elem: &'a Elem) => MinElem<'a, Elem>(list: Vec<Elem>)
+    where Elem: 'a;
   }
 
-  pub fn min<Elem, ListVal: HasType<Vec<Elem>>>(
+  pub fn min<'a, Elem, ListVal: HasType<Vec<Elem>>>(
     seed: impl Seed,
-    list: &Named<ListVal, Vec<Elem>>,
+    list: NamedRef<'a, ListVal, Vec<Elem>>,
     _sorted: Sorted<ListVal>,
     _non_empty: NonEmpty<Elem, ListVal>,
-  ) -> MinResult<Elem, ListVal, impl HasType<&Elem>>
+  ) -> ExistMinElem<'a, impl HasType<&'a Elem>, Elem, ListVal>
   {
     let elem = list.value().first().unwrap();
-
-    MinResult {
-      elem: seed.new_named(elem),
-      min_proof: MinElem(PhantomData),
-    }
+    new_exist_min_elem(seed, elem)
   }
 }
 
 mod lookup
 {
-  use core::marker::PhantomData;
   use std::collections::BTreeMap;
 
-  use mononym::{
-    HasType,
-    Name,
-    Named,
-    Seed,
-  };
+  use mononym::*;
 
-  pub struct HasKey<MapVal, KeyVal, ValueVal>(
-    PhantomData<(MapVal, KeyVal, ValueVal)>,
-  );
+  exists! {
+    ExistLookup<'a, Key, Value>(entry_value: &'a Value)
+    for (map: BTreeMap<Key, Value>, key: Key) =>
+    HasKey<'a, Key, Value>(map, key, entry_value)
+    where Key: 'a, Value: 'a;
+  }
 
-  pub struct LookupResult<
-    'a,
-    Value,
-    MapVal,
-    KeyVal,
-    ValueVal: HasType<&'a Value>,
-  > {
-    enry_value: Named<ValueVal, &'a Value>,
-    has_key_proof: HasKey<MapVal, KeyVal, ValueVal>,
+  proof! {
+    HasKey<'a, Key, Value>(
+      map: BTreeMap<Key, Value>,
+      key: Key,
+      entry_value: &'a Value,
+    ) where Key: 'a, Value: 'a;
   }
 
   pub fn lookup<
@@ -238,21 +218,19 @@ mod lookup
     KeyVal: HasType<Key>,
   >(
     seed: impl Seed,
-    map: &'a Named<MapVal, BTreeMap<Key, Value>>,
+    map: NamedRef<'a, MapVal, BTreeMap<Key, Value>>,
     key: &Named<KeyVal, Key>,
-  ) -> Option<LookupResult<'a, Value, MapVal, KeyVal, impl HasType<&'a Value>>>
+  ) -> Option<
+    ExistLookup<'a, impl HasType<&'a Value>, Key, Value, MapVal, KeyVal>,
+  >
   where
     Key: Ord,
     Value: Clone,
   {
-    map.value().get(key.value()).map(move |value| {
-      let value = seed.new_named(value);
-
-      LookupResult {
-        enry_value: value,
-        has_key_proof: HasKey(PhantomData),
-      }
-    })
+    map
+      .value()
+      .get(key.value())
+      .map(move |value| new_exist_lookup(seed, value))
   }
 }
 
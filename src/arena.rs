@@ -0,0 +1,225 @@
+/*!
+ An append-only arena branded by a unique lifetime `'name`, in the
+ style of [GhostCell](https://plv.mpi-sws.org/rustbelt/ghostcell/).
+ Since the arena is created by consuming the unique [`Life<'name>`](Life)
+ given by [`with_seed`](crate::with_seed), there can only be one
+ arena with the brand `'name`. As elements are never removed from
+ the arena, an [`Index<'name>`](Index) returned by the arena
+ is always valid, and lookups never need to be checked.
+*/
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::{
+  internal::Brand,
+  Life,
+};
+
+pub struct Arena<'name, T>
+{
+  elems: Vec<T>,
+  brand: Brand<'name>,
+}
+
+/**
+ An index into the [`Arena`] with the brand `'name`. The index
+ can only be used with the arena that produced it. For example,
+ the following code should fail to compile:
+
+ ```rust,compile_fail
+ # use mononym::{*, arena::*};
+ with_seed(|life1| {
+   with_seed(|life2| {
+     let mut arena1 = Arena::new(life1);
+     let arena2: Arena<u32> = Arena::new(life2);
+     let index = arena1.push(1);
+     arena2.get(index); // error
+   });
+ });
+ ```
+*/
+pub struct Index<'name>
+{
+  index: usize,
+  brand: Brand<'name>,
+}
+
+impl<'name> Clone for Index<'name>
+{
+  fn clone(&self) -> Self
+  {
+    *self
+  }
+}
+
+impl<'name> Copy for Index<'name> {}
+
+impl<'name> PartialEq for Index<'name>
+{
+  fn eq(
+    &self,
+    other: &Self,
+  ) -> bool
+  {
+    self.index == other.index
+  }
+}
+
+impl<'name> Eq for Index<'name> {}
+
+impl<'name> PartialOrd for Index<'name>
+{
+  fn partial_cmp(
+    &self,
+    other: &Self,
+  ) -> Option<core::cmp::Ordering>
+  {
+    Some(self.cmp(other))
+  }
+}
+
+impl<'name> Ord for Index<'name>
+{
+  fn cmp(
+    &self,
+    other: &Self,
+  ) -> core::cmp::Ordering
+  {
+    self.index.cmp(&other.index)
+  }
+}
+
+impl<'name> core::hash::Hash for Index<'name>
+{
+  fn hash<H: core::hash::Hasher>(
+    &self,
+    state: &mut H,
+  )
+  {
+    self.index.hash(state)
+  }
+}
+
+impl<'name> core::fmt::Debug for Index<'name>
+{
+  fn fmt(
+    &self,
+    f: &mut core::fmt::Formatter<'_>,
+  ) -> core::fmt::Result
+  {
+    f.debug_tuple("Index").field(&self.index).finish()
+  }
+}
+
+impl<'name> Index<'name>
+{
+  /**
+   Get the underlying position of the element in the arena.
+  */
+  pub fn into_usize(self) -> usize
+  {
+    self.index
+  }
+}
+
+impl<'name, T> Arena<'name, T>
+{
+  /**
+   Creates a new empty arena branded with the unique lifetime `'name`.
+
+   ```rust
+   # use mononym::{*, arena::*};
+   with_seed(|life| {
+     let mut arena = Arena::new(life);
+     let first = arena.push("first");
+     let second = arena.push("second");
+
+     *arena.get_mut(first) = "updated";
+
+     assert_eq!(*arena.get(first), "updated");
+     assert_eq!(*arena.get(second), "second");
+   });
+   ```
+  */
+  pub fn new(_life: Life<'name>) -> Self
+  {
+    Arena {
+      elems: Vec::new(),
+      brand: PhantomData,
+    }
+  }
+
+  /**
+   Appends a new element to the arena, and returns the index of
+   the element.
+  */
+  pub fn push(
+    &mut self,
+    value: T,
+  ) -> Index<'name>
+  {
+    let index = self.elems.len();
+    self.elems.push(value);
+
+    Index {
+      index,
+      brand: PhantomData,
+    }
+  }
+
+  pub fn get(
+    &self,
+    index: Index<'name>,
+  ) -> &T
+  {
+    // Safety: the index can only be produced by `push` of this arena,
+    // as the arena is the only one with the unique brand `'name`. Since
+    // elements are never removed, the index is always in bounds.
+    unsafe { self.elems.get_unchecked(index.index) }
+  }
+
+  pub fn get_mut(
+    &mut self,
+    index: Index<'name>,
+  ) -> &mut T
+  {
+    // Safety: same as `get`.
+    unsafe { self.elems.get_unchecked_mut(index.index) }
+  }
+
+  pub fn len(&self) -> usize
+  {
+    self.elems.len()
+  }
+
+  pub fn is_empty(&self) -> bool
+  {
+    self.elems.is_empty()
+  }
+
+  /**
+   Iterates over the indices of all elements in the arena.
+  */
+  pub fn indices(&self) -> impl Iterator<Item = Index<'name>>
+  {
+    (0..self.elems.len()).map(|index| Index {
+      index,
+      brand: PhantomData,
+    })
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (Index<'name>, &T)>
+  {
+    self.indices().zip(self.elems.iter())
+  }
+
+  /**
+   Consume the arena and turn it back into the underlying elements,
+   in the order they were pushed.
+  */
+  pub fn into_vec(self) -> Vec<T>
+  {
+    self.elems
+  }
+}
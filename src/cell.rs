@@ -0,0 +1,157 @@
+/*!
+ Branded interior mutability in the style of
+ [GhostCell](https://plv.mpi-sws.org/rustbelt/ghostcell/). The
+ permission to access a [`NamedCell<'name, T>`](NamedCell) is
+ separated into a [`Token<'name>`](Token), which is created by
+ consuming the unique [`Life<'name>`](Life) given by
+ [`with_seed`](crate::with_seed). Since there can only be one token
+ with the brand `'name`, reading the cells require `&Token<'name>`,
+ and mutating the cells require `&mut Token<'name>`, the usual
+ borrowing rules of Rust apply to all cells of the same brand at once.
+*/
+
+use core::{
+  cell::UnsafeCell,
+  marker::PhantomData,
+};
+
+use crate::{
+  internal::Brand,
+  Life,
+};
+
+/**
+ The unique permission to access all [`NamedCell`]s with the
+ brand `'name`.
+*/
+pub struct Token<'name>
+{
+  brand: Brand<'name>,
+}
+
+/**
+ A cell that can only be accessed through the [`Token`] with the same
+ brand `'name`. For example, the following code should fail to compile
+ because of the two overlapping mutable borrows of the token:
+
+ ```rust,compile_fail
+ # use mononym::{*, cell::*};
+ with_seed(|life| {
+   let mut token = Token::new(life);
+   let cell1 = NamedCell::new(1);
+   let cell2 = NamedCell::new(2);
+
+   let x = cell1.borrow_mut(&mut token);
+   let y = cell2.borrow_mut(&mut token); // error
+   *x += *y;
+ });
+ ```
+
+ Similarly, a cell cannot be accessed with a token of another brand:
+
+ ```rust,compile_fail
+ # use mononym::{*, cell::*};
+ with_seed(|life1| {
+   with_seed(|life2| {
+     let token1 = Token::new(life1);
+     let token2 = Token::new(life2);
+     let cell = NamedCell::new(1);
+
+     cell.borrow(&token1);
+     cell.borrow(&token2); // error
+   });
+ });
+ ```
+*/
+pub struct NamedCell<'name, T: ?Sized>
+{
+  brand: Brand<'name>,
+  value: UnsafeCell<T>,
+}
+
+// Safety: the cell can only be accessed through the token, so sharing
+// the cell across threads is the same as sharing the underlying value
+// together with the token, following the same rule as `RwLock`.
+unsafe impl<'name, T: ?Sized + Send + Sync> Sync for NamedCell<'name, T> {}
+
+impl<'name> Token<'name>
+{
+  /**
+   Turns the unique lifetime proxy into the unique token with the
+   brand `'name`.
+
+   ```rust
+   # use mononym::{*, cell::*};
+   with_seed(|life| {
+     let mut token = Token::new(life);
+     let cell = NamedCell::new(vec![1, 2]);
+     let shared = (&cell, &cell);
+
+     shared.0.borrow_mut(&mut token).push(3);
+
+     assert_eq!(shared.1.borrow(&token), &vec![1, 2, 3]);
+   });
+   ```
+  */
+  pub fn new(_life: Life<'name>) -> Self
+  {
+    Token { brand: PhantomData }
+  }
+}
+
+impl<'name, T> NamedCell<'name, T>
+{
+  pub fn new(value: T) -> Self
+  {
+    NamedCell {
+      brand: PhantomData,
+      value: UnsafeCell::new(value),
+    }
+  }
+
+  pub fn into_inner(self) -> T
+  {
+    self.value.into_inner()
+  }
+}
+
+impl<'name, T: ?Sized> NamedCell<'name, T>
+{
+  /**
+   Get a shared reference to the value, which is valid for as long
+   as the token is immutably borrowed.
+  */
+  pub fn borrow<'a>(
+    &'a self,
+    _token: &'a Token<'name>,
+  ) -> &'a T
+  {
+    // Safety: the token is immutably borrowed, so there can be no
+    // mutable reference to any cell with the same brand.
+    unsafe { &*self.value.get() }
+  }
+
+  /**
+   Get a mutable reference to the value, which is valid for as long
+   as the token is mutably borrowed.
+  */
+  #[allow(clippy::mut_from_ref)]
+  pub fn borrow_mut<'a>(
+    &'a self,
+    _token: &'a mut Token<'name>,
+  ) -> &'a mut T
+  {
+    // Safety: the token is mutably borrowed, so there can be no
+    // other reference to any cell with the same brand.
+    unsafe { &mut *self.value.get() }
+  }
+
+  /**
+   Get a mutable reference to the value without the token, which
+   is safe as the cell itself is mutably borrowed.
+  */
+  pub fn get_mut(&mut self) -> &mut T
+  {
+    self.value.get_mut()
+  }
+}
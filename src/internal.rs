@@ -0,0 +1,51 @@
+/*!
+ Crate-private items shared by the modules of `mononym`.
+*/
+
+use core::marker::PhantomData;
+
+/**
+ A phantom type that makes `'name` invariant, while keeping the
+ containing types `Send` and `Sync`.
+*/
+pub(crate) type Brand<'name> = PhantomData<fn(&'name ()) -> &'name ()>;
+
+/**
+ The supertrait of the public traits in `mononym` that cannot be
+ implemented outside of the crate, such as
+ [`Integer`](crate::proof::num::Integer),
+ [`Indexable`](crate::proof::index::Indexable) and
+ [`Sequence`](crate::proof::non_empty::Sequence). Since the trait
+ is defined in a private module, it cannot be named by other crates.
+*/
+pub trait Sealed {}
+
+macro_rules! impl_sealed {
+  ( $( $type:ty ),* ) => {
+    $( impl Sealed for $type {} )*
+  };
+}
+
+impl_sealed!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T, const N: usize> Sealed for [T; N] {}
+
+impl<T> Sealed for [T] {}
+
+impl<T> Sealed for &[T] {}
+
+impl Sealed for str {}
+
+impl Sealed for &str {}
+
+#[cfg(feature = "alloc")]
+impl<T> Sealed for alloc::vec::Vec<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T> Sealed for alloc::boxed::Box<[T]> {}
+
+#[cfg(feature = "alloc")]
+impl<T> Sealed for alloc::collections::VecDeque<T> {}
+
+#[cfg(feature = "alloc")]
+impl Sealed for alloc::string::String {}
@@ -1,5 +1,4 @@
 #![no_std]
-#![feature(generic_associated_types)]
 
 /*!
   Mononym is a library for creating unique type-level names for each value
@@ -29,15 +28,28 @@ pub mod named;
 
 pub mod proof;
 
+#[cfg(feature = "alloc")]
+pub mod arena;
+
+pub mod cell;
+
+pub mod sigma;
+
 #[doc(hidden)]
 pub mod macros;
 
+mod internal;
+
+#[cfg(feature = "alloc")]
+pub use named::NamedBox;
 pub use named::{
   with_seed,
+  AsNamedRef,
   HasType,
   Life,
   Name,
   Named,
+  NamedRef,
   Seed,
 };
 
@@ -58,7 +70,7 @@ pub mod docs
   {}
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
 
 #[cfg(test)]
@@ -1,51 +1,639 @@
 pub use paste::paste;
 
+/**
+ Implemented by the proof types generated by the macros, as the
+ supertrait of [`Proof`](crate::proof::Proof). The trait has to be
+ public for the macros to implement it in other crates, so it is
+ only hidden from the documentation, and is not meant to be
+ implemented directly.
+*/
+#[doc(hidden)]
+pub trait Sealed {}
+
+/**
+ Collects the predicates of a `where` clause until the next `with`,
+ `if`, `else` or `;`, and passes them in brackets to the callback
+ macro after the given prefix.
+*/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! where_clause {
+  ( [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $where:tt )* ]
+    with $( $rest:tt )*
+  ) => {
+    $( $callback )* ! {
+      $( $prefix )* [ $( $where )* ] with $( $rest )*
+    }
+  };
+  ( [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $where:tt )* ]
+    if $( $rest:tt )*
+  ) => {
+    $( $callback )* ! {
+      $( $prefix )* [ $( $where )* ] if $( $rest )*
+    }
+  };
+  ( [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $where:tt )* ]
+    else $( $rest:tt )*
+  ) => {
+    $( $callback )* ! {
+      $( $prefix )* [ $( $where )* ] else $( $rest )*
+    }
+  };
+  ( [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $where:tt )* ]
+    ; $( $rest:tt )*
+  ) => {
+    $( $callback )* ! {
+      $( $prefix )* [ $( $where )* ] ; $( $rest )*
+    }
+  };
+  ( [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $where:tt )* ]
+    $next:tt $( $rest:tt )*
+  ) => {
+    $crate::where_clause! {
+      [ $( $callback )* ]
+      [ $( $prefix )* ]
+      [ $( $where )* $next ]
+      $( $rest )*
+    }
+  };
+}
+
+/**
+ Parses a comma separated list of proof applications such as
+ `IsMin<'a, T>(elem, list)`, and passes them to the callback macro
+ after the given prefix in the form
+ `[ { IsMin ['a] [T] (elem, list) } ... ]`, followed by the
+ remaining tokens.
+
+ Lifetime arguments are parsed one at a time, as a lifetime can
+ also start a type and would otherwise be ambiguous.
+*/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! proof_list {
+  ( @generics
+    [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $acc:tt )* ]
+    $proof:ident
+    [ $( $proof_lt:lifetime ),* ]
+    $next_lt:lifetime , $( $rest:tt )*
+  ) => {
+    $crate::proof_list! {
+      @generics
+      [ $( $callback )* ]
+      [ $( $prefix )* ]
+      [ $( $acc )* ]
+      $proof
+      [ $( $proof_lt, )* $next_lt ]
+      $( $rest )*
+    }
+  };
+  ( @generics
+    [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $acc:tt )* ]
+    $proof:ident
+    [ $( $proof_lt:lifetime ),* ]
+    $next_lt:lifetime >
+    ( $( $proof_arg:ident ),* $(,)? )
+    $( $rest:tt )*
+  ) => {
+    $crate::proof_list! {
+      @next
+      [ $( $callback )* ]
+      [ $( $prefix )* ]
+      [
+        $( $acc )*
+        { $proof [ $( $proof_lt, )* $next_lt ] [] ( $( $proof_arg ),* ) }
+      ]
+      $( $rest )*
+    }
+  };
+  ( @generics
+    [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $acc:tt )* ]
+    $proof:ident
+    [ $( $proof_lt:lifetime ),* ]
+    $( $proof_param:ty ),+ $(,)? >
+    ( $( $proof_arg:ident ),* $(,)? )
+    $( $rest:tt )*
+  ) => {
+    $crate::proof_list! {
+      @next
+      [ $( $callback )* ]
+      [ $( $prefix )* ]
+      [
+        $( $acc )*
+        {
+          $proof
+          [ $( $proof_lt ),* ]
+          [ $( $proof_param ),* ]
+          ( $( $proof_arg ),* )
+        }
+      ]
+      $( $rest )*
+    }
+  };
+  ( @next
+    [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $acc:tt )* ]
+    , $( $rest:tt )*
+  ) => {
+    $crate::proof_list! {
+      [ $( $callback )* ]
+      [ $( $prefix )* ]
+      [ $( $acc )* ]
+      $( $rest )*
+    }
+  };
+  ( @next
+    [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $acc:tt )* ]
+    $( $rest:tt )*
+  ) => {
+    $( $callback )* ! {
+      $( $prefix )* [ $( $acc )* ] $( $rest )*
+    }
+  };
+  ( [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $acc:tt )* ]
+    $proof:ident < $next_lt:lifetime
+    $( $rest:tt )*
+  ) => {
+    $crate::proof_list! {
+      @generics
+      [ $( $callback )* ]
+      [ $( $prefix )* ]
+      [ $( $acc )* ]
+      $proof
+      []
+      $next_lt
+      $( $rest )*
+    }
+  };
+  ( [ $( $callback:tt )* ]
+    [ $( $prefix:tt )* ]
+    [ $( $acc:tt )* ]
+    $proof:ident
+    $( < $( $proof_param:ty ),+ $(,)? > )?
+    ( $( $proof_arg:ident ),* $(,)? )
+    $( $rest:tt )*
+  ) => {
+    $crate::proof_list! {
+      @next
+      [ $( $callback )* ]
+      [ $( $prefix )* ]
+      [
+        $( $acc )*
+        {
+          $proof
+          []
+          [ $( $( $proof_param ),* )? ]
+          ( $( $proof_arg ),* )
+        }
+      ]
+      $( $rest )*
+    }
+  };
+}
+
 #[doc = include_str!("../docs/exists_macro.md")]
 #[macro_export]
 macro_rules! exists {
-  ( $(
-      $exists:ident
-      ( $name:ident : $type:ty ) =>
-      $proof:ident
-      $( < $( $proof_param:ident ),+ $(,) ? > )?
-      ( $( $suchthat:ident $( : $suchtype:ty )? ),* $(,)? );
-    )*
+  () => {};
+  ( @item
+    [ $( $vis:tt )* ]
+    [ $( $attr:tt )* ]
+    $exists:ident
+    ( $name:ident : $type:ty ) =>
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident : $suchtype:ty ),* $(,)? )
+    $( where [ $( $where:tt )* ] )?
+    $( with $( pub $( ( $( $ctor_vis:tt )* ) )? )? fn $ctor:ident )?;
+    $( $rest:tt )*
+  ) => {
+    $crate::exists_single! {
+      [ $( $attr )* ]
+      [ $( $vis )* ]
+      [ $( $( $where )* )? ]
+      $exists
+      ( $name : $type ) =>
+      $proof
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat : $suchtype ),* )
+      [ $( $( pub $( ( $( $ctor_vis )* ) )? )? fn $ctor )? ]
+    }
 
+    $crate::exists! { $( $rest )* }
+  };
+  ( @item
+    [ $( $vis:tt )* ]
+    [ $( $attr:tt )* ]
+    $exists:ident
+    ( $name:ident : $type:ty ) =>
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident : $suchtype:ty ),* $(,)? )
+    where $( $rest:tt )*
   ) => {
-    $(
-      $crate::exists_single! {
+    $crate::where_clause! {
+      [ $crate::exists ]
+      [
+        @item
+        [ $( $vis )* ]
+        [ $( $attr )* ]
         $exists
         ( $name : $type ) =>
         $proof
-        $( < $( $proof_param ),* > )*
-        ( $( $suchthat $( : $suchtype )* ),* );
+        $( < $( $proof_lt, )* $( $proof_param ),* > )?
+        ( $( $suchthat : $suchtype ),* )
+        where
+      ]
+      []
+      $( $rest )*
+    }
+  };
+  ( @item
+    [ $( $vis:tt )* ]
+    [ $( $attr:tt )* ]
+    $exists:ident
+    $( <
+      $( $exists_lt:lifetime ),* $(,)?
+      $( $exists_param:ident ),* $(,)?
+    > )?
+    ( $( $name:ident : $type:ty ),+ $(,)? )
+    $( for ( $( $suchthat:ident : $suchtype:ty ),* $(,)? ) )?
+    =>
+    $( $rest:tt )*
+  ) => {
+    $crate::proof_list! {
+      [ $crate::exists ]
+      [
+        @multi
+        [ $( $vis )* ]
+        [ $( $attr )* ]
+        [
+          $exists
+          [ $( $( $exists_lt ),* )? ]
+          [ $( $( $exists_param ),* )? ]
+          ( $( $name : $type ),* )
+          for ( $( $( $suchthat : $suchtype ),* )? )
+        ]
+        =>
+      ]
+      []
+      $( $rest )*
+    }
+  };
+  ( @multi
+    [ $( $vis:tt )* ]
+    [ $( $attr:tt )* ]
+    [
+      $exists:ident
+      [ $( $exists_lt:lifetime ),* ]
+      [ $( $exists_param:ident ),* ]
+      ( $( $name:ident : $type:ty ),+ )
+      for ( $( $suchthat:ident : $suchtype:ty ),* )
+    ]
+    =>
+    [ $(
+      {
+        $proof:ident
+        [ $( $proof_lt:lifetime ),* ]
+        [ $( $proof_param:ty ),* ]
+        ( $( $proof_arg:ident ),* )
       }
-    )*
-  }
+    )+ ]
+    $( where [ $( $where:tt )* ] )?
+    $( with $( pub $( ( $( $ctor_vis:tt )* ) )? )? fn $ctor:ident )?;
+    $( $rest:tt )*
+  ) => {
+    $crate::exists_multi! {
+      [ $( $attr )* ]
+      [ $( $vis )* ]
+      [ $( $( $where )* )? ]
+      $exists
+      [ $( $exists_lt ),* ]
+      [ $( $exists_param ),* ]
+      ( $( $name : $type ),* )
+      for ( $( $suchthat : $suchtype ),* )
+      =>
+      $(
+        {
+          $proof
+          [ $( $proof_lt ),* ]
+          [ $( $proof_param ),* ]
+          ( $( $proof_arg ),* )
+        }
+      )+
+      [ $( $( pub $( ( $( $ctor_vis )* ) )? )? fn $ctor )? ]
+    }
+
+    $crate::exists! { $( $rest )* }
+  };
+  ( @multi
+    [ $( $vis:tt )* ]
+    [ $( $attr:tt )* ]
+    [ $( $head:tt )* ]
+    =>
+    [ $( $proofs:tt )* ]
+    where $( $rest:tt )*
+  ) => {
+    $crate::where_clause! {
+      [ $crate::exists ]
+      [
+        @multi
+        [ $( $vis )* ]
+        [ $( $attr )* ]
+        [ $( $head )* ]
+        =>
+        [ $( $proofs )* ]
+        where
+      ]
+      []
+      $( $rest )*
+    }
+  };
+  ( $( #[$attr:meta] )*
+    pub ( $( $vis:tt )* )
+    $exists:ident
+    $( $rest:tt )*
+  ) => {
+    $crate::exists! {
+      @item [ pub ( $( $vis )* ) ] [ $( #[$attr] )* ] $exists $( $rest )*
+    }
+  };
+  ( $( #[$attr:meta] )*
+    pub
+    $exists:ident
+    $( $rest:tt )*
+  ) => {
+    $crate::exists! {
+      @item [ pub ] [ $( #[$attr] )* ] $exists $( $rest )*
+    }
+  };
+  ( $( #[$attr:meta] )*
+    $exists:ident
+    $( $rest:tt )*
+  ) => {
+    $crate::exists! {
+      @item [ pub ] [ $( #[$attr] )* ] $exists $( $rest )*
+    }
+  };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! exists_multi {
+  ( @constructor
+    []
+    $exists:ident
+    $( $rest:tt )*
+  ) => {
+    $crate::exists_multi! {
+      @constructor
+      [ fn [< new_ $exists:snake >] ]
+      $exists
+      $( $rest )*
+    }
+  };
+  ( @constructor
+    [ $( pub $( ( $( $ctor_vis:tt )* ) )? )? fn $( $ctor:tt )+ ]
+    $exists:ident
+    [ $( $where:tt )* ]
+    [ $( $exists_lt:lifetime ),* ]
+    [ $( $exists_param:ident ),* ]
+    ( $( $name:ident : $type:ty ),+ )
+    for ( $( $suchthat:ident : $suchtype:ty ),* )
+    => $( { $proof:ident $( $proof_rest:tt )* } )+
+  ) => {
+    $crate::macros::paste! {
+      #[allow(dead_code)]
+      $( pub $( ( $( $ctor_vis )* ) )? )?
+      fn $( $ctor )+
+      <
+        $( $exists_lt, )*
+        $( $exists_param, )*
+        $( [< $suchthat:camel Val >] : $crate::HasType<$suchtype> ),*
+      >
+      (
+        seed : impl $crate::Seed,
+        $( [< $name:snake >] : $type, )+
+      ) ->
+        [< $exists:camel >]
+        <
+          $( $exists_lt, )*
+          $( impl $crate::HasType<$type>, )+
+          $( $exists_param, )*
+          $( [< $suchthat:camel Val >] ),*
+        >
+      where
+        $( $where )*
+      {
+        $(
+          let ( [< $name:snake _seed >], seed ) = $crate::Seed::replicate(seed);
+        )+
+        let _ = seed;
+
+        [< $exists:camel >] {
+          $(
+            [< $name:snake >] : $crate::Seed::new_named(
+              [< $name:snake _seed >],
+              [< $name:snake >]
+            ),
+          )+
+          $(
+            [< $proof:snake >] : [< $proof:camel >] (
+              ::core::marker::PhantomData
+            ),
+          )+
+        }
+      }
+    }
+  };
+  ( [ $( $attr:tt )* ]
+    [ $( $vis:tt )* ]
+    [ $( $where:tt )* ]
+    $exists:ident
+    [ $( $exists_lt:lifetime ),* ]
+    [ $( $exists_param:ident ),* ]
+    ( $( $name:ident : $type:ty ),+ )
+    for ( $( $suchthat:ident : $suchtype:ty ),* )
+    =>
+    $(
+      {
+        $proof:ident
+        [ $( $proof_lt:lifetime ),* ]
+        [ $( $proof_param:ty ),* ]
+        ( $( $proof_arg:ident ),* )
+      }
+    )+
+    [ $( $ctor:tt )* ]
+  ) => {
+    $crate::macros::paste! {
+      $( $attr )*
+      $( $vis )* struct [< $exists:camel >]
+      <
+        $( $exists_lt, )*
+        $( [< $name:camel Val >] : $crate::HasType<$type>, )+
+        $( $exists_param, )*
+        $( [< $suchthat:camel Val >] : $crate::HasType<$suchtype> ),*
+      >
+      where
+        $( $where )*
+      {
+        $(
+          pub [< $name:snake >] : $crate::Named<
+            [< $name:camel Val >],
+            $type
+          >,
+        )+
+        $(
+          pub [< $proof:snake >] :
+            [< $proof:camel >]
+            <
+              $( $proof_lt, )*
+              $( $proof_param, )*
+              $( [< $proof_arg:camel Val >] ),*
+            >,
+        )+
+      }
+    }
+
+    $crate::exists_multi! {
+      @constructor
+      [ $( $ctor )* ]
+      $exists
+      [ $( $where )* ]
+      [ $( $exists_lt ),* ]
+      [ $( $exists_param ),* ]
+      ( $( $name : $type ),* )
+      for ( $( $suchthat : $suchtype ),* )
+      =>
+      $(
+        {
+          $proof
+          [ $( $proof_lt ),* ]
+          [ $( $proof_param ),* ]
+          ( $( $proof_arg ),* )
+        }
+      )+
+    }
+  };
+}
+
+#[doc(hidden)]
 #[macro_export]
 macro_rules! exists_single {
-  ( $exists:ident
+  ( @constructor
+    []
+    $exists:ident
+    $( $rest:tt )*
+  ) => {
+    $crate::exists_single! {
+      @constructor
+      [ fn [< new_ $exists:snake >] ]
+      $exists
+      $( $rest )*
+    }
+  };
+  ( @constructor
+    [ $( pub $( ( $( $ctor_vis:tt )* ) )? )? fn $( $ctor:tt )+ ]
+    $exists:ident
+    [ $( $where:tt )* ]
     ( $name:ident : $type:ty ) =>
     $proof:ident
-    $( < $( $proof_param:ident ),+ $(,) ? > )?
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident $( : $suchtype:ty )? ),* )
+  ) => {
+    $crate::macros::paste! {
+      #[allow(dead_code)]
+      $( pub $( ( $( $ctor_vis )* ) )? )?
+      fn $( $ctor )+
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
+      >
+      (
+        seed : impl $crate::Seed,
+        [< $name:snake >] : $type,
+      ) ->
+        [< $exists:camel >]
+        <
+          $( $( $proof_lt, )* )?
+          impl $crate::HasType<$type>,
+          $( $( $proof_param, )* )?
+          $( [< $suchthat:camel Val >] ),*
+        >
+      where
+        $( $where )*
+      {
+        [< $exists:camel >] {
+          [< $name:snake >]: $crate::Seed::new_named(seed, [< $name:snake >]),
+          [< $proof:snake >] : [< $proof:camel >] ( ::core::marker::PhantomData )
+        }
+      }
+    }
+  };
+  ( [ $( $attr:tt )* ]
+    [ $( $vis:tt )* ]
+    [ $( $where:tt )* ]
+    $exists:ident
+    ( $name:ident : $type:ty ) =>
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
     ( $( $suchthat:ident $( : $suchtype:ty )? ),* $(,)? )
-    $(;)?
+    [ $( $ctor:tt )* ]
   ) => {
     $crate::proof_single! {
+      []
+      [ $( $vis )* ]
+      [ $( $where )* ]
       $proof
-      $( < $( $proof_param ),* > )?
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
       ( $name : $type, $( $suchthat $( : $suchtype )? ),* )
+      []
     }
 
     $crate::macros::paste! {
-      pub struct [< $exists:camel >]
+      $( $attr )*
+      $( $vis )* struct [< $exists:camel >]
       <
+        $( $( $proof_lt, )* )?
         [< $name:camel Val >] : $crate::HasType<$type>,
         $( $( $proof_param, )* )?
         $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
       >
+      where
+        $( $where )*
       {
         pub [< $name:snake >] : $crate::Named<
           [< $name:camel Val >],
@@ -54,92 +642,1208 @@ macro_rules! exists_single {
         pub [< $proof:snake >] :
           [< $proof:camel >]
           <
-            $( $( $proof_param, )* )?
+            $( $( $proof_lt, )* $( $proof_param, )* )?
             [< $name:camel Val >],
             $( [< $suchthat:camel Val >] ),*
           >,
       }
+    }
+
+    $crate::exists_single! {
+      @constructor
+      [ $( $ctor )* ]
+      $exists
+      [ $( $where )* ]
+      ( $name : $type ) =>
+      $proof
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat $( : $suchtype )? ),* )
+    }
+  };
+}
+
+#[doc = include_str!("../docs/proof_macro.md")]
+#[macro_export]
+macro_rules! proof {
+  () => {};
+  ( @item
+    [ $( $vis:tt )* ]
+    [ $( $attr:tt )* ]
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident $( : $suchtype:ty )? ),* $(,)? )
+    $( where [ $( $where:tt )* ] )?
+    $( with $( pub $( ( $( $ctor_vis:tt )* ) )? )? fn $ctor:ident )?
+    if $predicate:expr, else $negation:ident;
+    $( $rest:tt )*
+  ) => {
+    $crate::proof_single! {
+      [ $( $attr )* ]
+      [ $( $vis )* ]
+      [ $( $( $where )* )? ]
+      $proof
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat $( : $suchtype )? ),* )
+      [ $( $( pub $( ( $( $ctor_vis )* ) )? )? fn $ctor )? ]
+      if $predicate, else $negation;
+    }
+
+    $crate::proof! { $( $rest )* }
+  };
+  ( @item
+    [ $( $vis:tt )* ]
+    [ $( $attr:tt )* ]
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident $( : $suchtype:ty )? ),* $(,)? )
+    $( where [ $( $where:tt )* ] )?
+    $( with $( pub $( ( $( $ctor_vis:tt )* ) )? )? fn $ctor:ident )?
+    if $predicate:expr;
+    $( $rest:tt )*
+  ) => {
+    $crate::proof_single! {
+      [ $( $attr )* ]
+      [ $( $vis )* ]
+      [ $( $( $where )* )? ]
+      $proof
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat $( : $suchtype )? ),* )
+      [ $( $( pub $( ( $( $ctor_vis )* ) )? )? fn $ctor )? ]
+      if $predicate;
+    }
+
+    $crate::proof! { $( $rest )* }
+  };
+  ( @item
+    [ $( $vis:tt )* ]
+    [ $( $attr:tt )* ]
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident $( : $suchtype:ty )? ),* $(,)? )
+    $( where [ $( $where:tt )* ] )?
+    $( with $( pub $( ( $( $ctor_vis:tt )* ) )? )? fn $ctor:ident )?
+    $( else $negation:ident )?;
+    $( $rest:tt )*
+  ) => {
+    $crate::proof_single! {
+      [ $( $attr )* ]
+      [ $( $vis )* ]
+      [ $( $( $where )* )? ]
+      $proof
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat $( : $suchtype )? ),* )
+      [ $( $( pub $( ( $( $ctor_vis )* ) )? )? fn $ctor )? ]
+      $( else $negation )?;
+    }
+
+    $crate::proof! { $( $rest )* }
+  };
+  ( @item
+    [ $( $vis:tt )* ]
+    [ $( $attr:tt )* ]
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident $( : $suchtype:ty )? ),* $(,)? )
+    where $( $rest:tt )*
+  ) => {
+    $crate::where_clause! {
+      [ $crate::proof ]
+      [
+        @item
+        [ $( $vis )* ]
+        [ $( $attr )* ]
+        $proof
+        $( < $( $proof_lt, )* $( $proof_param ),* > )?
+        ( $( $suchthat $( : $suchtype )? ),* )
+        where
+      ]
+      []
+      $( $rest )*
+    }
+  };
+  ( $( #[$attr:meta] )*
+    pub ( $( $vis:tt )* )
+    $proof:ident
+    $( $rest:tt )*
+  ) => {
+    $crate::proof! {
+      @item [ pub ( $( $vis )* ) ] [ $( #[$attr] )* ] $proof $( $rest )*
+    }
+  };
+  ( $( #[$attr:meta] )*
+    pub
+    $proof:ident
+    $( $rest:tt )*
+  ) => {
+    $crate::proof! {
+      @item [ pub ] [ $( #[$attr] )* ] $proof $( $rest )*
+    }
+  };
+  ( $( #[$attr:meta] )*
+    $proof:ident
+    $( $rest:tt )*
+  ) => {
+    $crate::proof! {
+      @item [ pub ] [ $( #[$attr] )* ] $proof $( $rest )*
+    }
+  };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! proof_single {
+  ( [ $( $attr:tt )* ]
+    [ $( $vis:tt )* ]
+    [ $( $where:tt )* ]
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident : $suchtype:ty ),* $(,)? )
+    [ $( $ctor:tt )* ]
+    if $predicate:expr, else $negation:ident
+    $(;)?
+  ) => {
+    $crate::proof_single! {
+      [ $( $attr )* ]
+      [ $( $vis )* ]
+      [ $( $where )* ]
+      $proof
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat : $suchtype ),* )
+      [ $( $ctor )* ]
+      if $predicate;
+    }
+
+    $crate::proof_single! {
+      @negation
+      [ $( $vis )* ]
+      [ $( $where )* ]
+      $proof
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat : $suchtype ),* )
+      else $negation
+    }
+
+    $crate::macros::paste! {
+      $( $vis )* fn [< decide_ $proof:snake >]
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >] : $crate::HasType<$suchtype> ),*
+      >
+      (
+        $( $suchthat : impl $crate::AsNamedRef< [< $suchthat:camel Val >], $suchtype > ),*
+      ) ->
+        $crate::proof::logic::Decision<
+          [< $proof:camel >]
+          <
+            $( $( $proof_lt, )* $( $proof_param, )* )?
+            $( [< $suchthat:camel Val >] ),*
+          >
+        >
+      where
+        $( $where )*
+      {
+        match [< check_ $proof:snake >]( $( $suchthat ),* ) {
+          Some(proof) => $crate::proof::logic::Decision::Proved(proof),
+          None => $crate::proof::logic::Decision::Refuted(
+            [< $proof:camel >]::refute()
+          ),
+        }
+      }
+    }
+  };
+  ( [ $( $attr:tt )* ]
+    [ $( $vis:tt )* ]
+    [ $( $where:tt )* ]
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident : $suchtype:ty ),* $(,)? )
+    [ $( $ctor:tt )* ]
+    if $predicate:expr
+    $(;)?
+  ) => {
+    $crate::proof_single! {
+      [ $( $attr )* ]
+      [ $( $vis )* ]
+      [ $( $where )* ]
+      $proof
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat : $suchtype ),* )
+      [ $( $ctor )* ]
+    }
 
-      fn [< new_ $exists:snake >]
+    $crate::macros::paste! {
+      $( $vis )* fn [< check_ $proof:snake >]
       <
-        $( $( $proof_param, )* )?
-        $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >] : $crate::HasType<$suchtype> ),*
       >
       (
-        seed : impl $crate::Seed,
-        [< $name:snake >] : $type,
+        $( $suchthat : impl $crate::AsNamedRef< [< $suchthat:camel Val >], $suchtype > ),*
       ) ->
-        [< $exists:camel >]
-        < impl $crate::HasType<$type>,
-          $( $( $proof_param, )* )?
-          $( [< $suchthat:camel Val >] ),*
+        ::core::option::Option<
+          [< $proof:camel >]
+          <
+            $( $( $proof_lt, )* $( $proof_param, )* )?
+            $( [< $suchthat:camel Val >] ),*
+          >
         >
+      where
+        $( $where )*
       {
-        [< $exists:camel >] {
-          [< $name:snake >]: $crate::Seed::new_named(seed, [< $name:snake >]),
-          [< $proof:snake >] : [< $proof:camel >] ( ::core::marker::PhantomData )
+        $(
+          #[allow(unused_variables)]
+          let $suchthat : & $suchtype = $suchthat.as_named_ref().value();
+        )*
+
+        if $predicate {
+          ::core::option::Option::Some(
+            [< $proof:camel >] ( ::core::marker::PhantomData )
+          )
+        } else {
+          ::core::option::Option::None
         }
       }
     }
-  }
-}
+  };
+  ( [ $( $attr:tt )* ]
+    [ $( $vis:tt )* ]
+    [ $( $where:tt )* ]
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident $( : $suchtype:ty )? ),* $(,)? )
+    [ $( $ctor:tt )* ]
+    else $negation:ident
+    $(;)?
+  ) => {
+    $crate::proof_single! {
+      [ $( $attr )* ]
+      [ $( $vis )* ]
+      [ $( $where )* ]
+      $proof
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat $( : $suchtype )? ),* )
+      [ $( $ctor )* ]
+    }
 
-#[doc = include_str!("../docs/proof_macro.md")]
-#[macro_export]
-macro_rules! proof {
-  ( $(
-      $proof:ident
-      $( < $( $proof_param:ident ),+ $(,) ? > )?
-      ( $( $suchthat:ident $( : $suchtype:ty )? ),* $(,)? );
-    )+
+    $crate::proof_single! {
+      @negation
+      [ $( $vis )* ]
+      [ $( $where )* ]
+      $proof
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat $( : $suchtype )? ),* )
+      else $negation
+    }
+  };
+  ( @negation
+    [ $( $vis:tt )* ]
+    [ $( $where:tt )* ]
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident $( : $suchtype:ty )? ),* $(,)? )
+    else $negation:ident
   ) => {
-    $(
-      $crate::proof_single! {
-        $proof
-        $( < $( $proof_param ),* > )?
-        ( $( $suchthat $( : $suchtype )? ),* );
+    $crate::proof_single! {
+      []
+      [ $( $vis )* ]
+      [ $( $where )* ]
+      $negation
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat $( : $suchtype )? ),* )
+      []
+    }
+
+    $crate::macros::paste! {
+      impl
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
+      >
+      $crate::proof::logic::Negatable for
+      [< $proof:camel >]
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >]  ),*
+      >
+      where
+        $( $where )*
+      {
+        type Negation = [< $negation:camel >]
+        <
+          $( $( $proof_lt, )* $( $proof_param, )* )?
+          $( [< $suchthat:camel Val >]  ),*
+        >;
       }
-    )*
-  }
-}
 
-#[macro_export]
-macro_rules! proof_single {
-  ( $proof:ident
-    $( < $( $proof_param:ident ),+ $(,) ? > )?
+      impl
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
+      >
+      [< $proof:camel >]
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >]  ),*
+      >
+      where
+        $( $where )*
+      {
+        #[allow(dead_code)]
+        fn refute () -> $crate::proof::logic::Not<Self>
+        {
+          $crate::proof::logic::Not::new(
+            [< $negation:camel >] ( ::core::marker::PhantomData )
+          )
+        }
+      }
+    }
+  };
+  ( @constructor
+    []
+    $( $rest:tt )*
+  ) => {
+    $crate::proof_single! {
+      @constructor
+      [ fn new ]
+      $( $rest )*
+    }
+  };
+  ( @constructor
+    [ $( pub $( ( $( $ctor_vis:tt )* ) )? )? fn $ctor:ident ]
+    [ $( $where:tt )* ]
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
+    ( $( $suchthat:ident $( : $suchtype:ty )? ),* )
+  ) => {
+    $crate::macros::paste! {
+      impl
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
+      >
+      [< $proof:camel >]
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >]  ),*
+      >
+      where
+        $( $where )*
+      {
+        #[allow(dead_code)]
+        $( pub $( ( $( $ctor_vis )* ) )? )?
+        fn $ctor () -> Self
+        {
+          [< $proof:camel >] (
+            ::core::marker::PhantomData
+          )
+        }
+      }
+    }
+  };
+  ( [ $( $attr:tt )* ]
+    [ $( $vis:tt )* ]
+    [ $( $where:tt )* ]
+    $proof:ident
+    $( <
+      $( $proof_lt:lifetime ),* $(,)?
+      $( $proof_param:ident ),* $(,)?
+    > )?
     ( $( $suchthat:ident $( : $suchtype:ty )? ),* $(,)? )
+    [ $( $ctor:tt )* ]
     $(;)?
   ) => {
     $crate::macros::paste! {
-      pub struct [< $proof:camel >] <
-        $( $( $proof_param, )* )?
+      $( $attr )*
+      $( $vis )* struct [< $proof:camel >] <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
         $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
       >
       (
-        ::core::marker::PhantomData<(
-          $( $( $proof_param, )* )?
+        ::core::marker::PhantomData<fn() -> (
+          $( $( & $proof_lt (), )*
+            $( ::core::marker::PhantomData<$proof_param>, )* )?
           $( [< $suchthat:camel Val >] ),*
         )>
-      );
+      )
+      where
+        $( $where )*;
 
       impl
       <
-        $( $( $proof_param, )* )?
+        $( $( $proof_lt, )* $( $proof_param, )* )?
         $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
       >
+      ::core::clone::Clone for
       [< $proof:camel >]
       <
-        $( $( $proof_param, )* )?
+        $( $( $proof_lt, )* $( $proof_param, )* )?
         $( [< $suchthat:camel Val >]  ),*
       >
+      where
+        $( $where )*
       {
-        fn new () -> Self
+        fn clone (&self) -> Self
         {
-          [< $proof:camel >] (
-            ::core::marker::PhantomData
-          )
+          *self
+        }
+      }
+
+      impl
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
+      >
+      ::core::marker::Copy for
+      [< $proof:camel >]
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >]  ),*
+      >
+      where
+        $( $where )*
+      {}
+
+      impl
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
+      >
+      ::core::fmt::Debug for
+      [< $proof:camel >]
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >]  ),*
+      >
+      where
+        $( $where )*
+      {
+        fn fmt (
+          &self,
+          f: &mut ::core::fmt::Formatter<'_>,
+        ) -> ::core::fmt::Result
+        {
+          f.write_str(stringify!([< $proof:camel >]))
+        }
+      }
+
+      impl
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
+      >
+      $crate::macros::Sealed for
+      [< $proof:camel >]
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >]  ),*
+      >
+      where
+        $( $where )*
+      {}
+
+      impl
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >] $( : $crate::HasType<$suchtype> )?  ),*
+      >
+      $crate::proof::Proof for
+      [< $proof:camel >]
+      <
+        $( $( $proof_lt, )* $( $proof_param, )* )?
+        $( [< $suchthat:camel Val >]  ),*
+      >
+      where
+        $( $where )*
+      {
+        type Subjects = ( $( [< $suchthat:camel Val >], )* );
+
+        const NAME: &'static str = stringify!([< $proof:camel >]);
+
+        const DESCRIPTION: &'static str = stringify!(
+          $proof
+          $( < $( $proof_lt, )* $( $proof_param ),* > )?
+          ( $( $suchthat $( : $suchtype )? ),* )
+        );
+      }
+    }
+
+    $crate::proof_single! {
+      @constructor
+      [ $( $ctor )* ]
+      [ $( $where )* ]
+      $proof
+      $( < $( $proof_lt, )* $( $proof_param ),* > )?
+      ( $( $suchthat $( : $suchtype )? ),* )
+    }
+
+    $crate::proof_family! {
+      [ $( $vis )* ]
+      [ $( $where )* ]
+      $proof
+      [ $( $( $proof_lt ),* )? ]
+      [ $( $( $proof_param ),* )? ]
+      []
+      ( $( $suchthat $( : $suchtype )? ),* )
+    }
+  };
+}
+
+/**
+ Generates the [`ProofFamily`](crate::sigma::ProofFamily) of
+ a proof for each typed argument position, named after the proof
+ and the argument as `ProofAtArg`. The names of the other arguments
+ are the type parameters of the family, in the same order as in the
+ proof type.
+*/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! proof_family {
+  ( [ $( $vis:tt )* ]
+    [ $( $where:tt )* ]
+    $proof:ident
+    [ $( $proof_lt:lifetime ),* ]
+    [ $( $proof_param:ident ),* ]
+    [ $( $done:ident $( : $done_type:ty )? ),* ]
+    ( $current:ident : $current_type:ty
+      $( , $rest:ident $( : $rest_type:ty )? )*
+    )
+  ) => {
+    $crate::macros::paste! {
+      #[doc = concat!(
+        "The proof family of [`", stringify!($proof), "`] with the name of `",
+        stringify!($current), "` left open."
+      )]
+      #[allow(dead_code)]
+      $( $vis )* struct [< $proof:camel At $current:camel >] <
+        $( $proof_lt, )*
+        $( $proof_param, )*
+        $( [< $done:camel Val >] $( : $crate::HasType<$done_type> )?, )*
+        $( [< $rest:camel Val >] $( : $crate::HasType<$rest_type> )?, )*
+      >
+      (
+        ::core::marker::PhantomData<fn() -> (
+          $( & $proof_lt (), )*
+          $( ::core::marker::PhantomData<$proof_param>, )*
+          $( [< $done:camel Val >], )*
+          $( [< $rest:camel Val >], )*
+        )>
+      )
+      where
+        $( $where )*;
+
+      impl <
+        $( $proof_lt, )*
+        $( $proof_param, )*
+        $( [< $done:camel Val >] $( : $crate::HasType<$done_type> )?, )*
+        $( [< $rest:camel Val >] $( : $crate::HasType<$rest_type> )?, )*
+      >
+      $crate::sigma::ProofFamily<$current_type> for
+      [< $proof:camel At $current:camel >] <
+        $( $proof_lt, )*
+        $( $proof_param, )*
+        $( [< $done:camel Val >], )*
+        $( [< $rest:camel Val >], )*
+      >
+      where
+        $( $where )*
+      {
+        type Proof<N: $crate::HasType<$current_type>> = [< $proof:camel >] <
+          $( $proof_lt, )*
+          $( $proof_param, )*
+          $( [< $done:camel Val >], )*
+          N,
+          $( [< $rest:camel Val >], )*
+        >;
+      }
+    }
+
+    $crate::proof_family! {
+      [ $( $vis )* ]
+      [ $( $where )* ]
+      $proof
+      [ $( $proof_lt ),* ]
+      [ $( $proof_param ),* ]
+      [ $( $done $( : $done_type )?, )* $current : $current_type ]
+      ( $( $rest $( : $rest_type )? ),* )
+    }
+  };
+  ( [ $( $vis:tt )* ]
+    [ $( $where:tt )* ]
+    $proof:ident
+    [ $( $proof_lt:lifetime ),* ]
+    [ $( $proof_param:ident ),* ]
+    [ $( $done:ident $( : $done_type:ty )? ),* ]
+    ( $current:ident $( , $rest:ident $( : $rest_type:ty )? )* )
+  ) => {
+    $crate::proof_family! {
+      [ $( $vis )* ]
+      [ $( $where )* ]
+      $proof
+      [ $( $proof_lt ),* ]
+      [ $( $proof_param ),* ]
+      [ $( $done $( : $done_type )?, )* $current ]
+      ( $( $rest $( : $rest_type )? ),* )
+    }
+  };
+  ( [ $( $vis:tt )* ]
+    [ $( $where:tt )* ]
+    $proof:ident
+    [ $( $proof_lt:lifetime ),* ]
+    [ $( $proof_param:ident ),* ]
+    [ $( $done:tt )* ]
+    ()
+  ) => {};
+}
+
+#[doc = include_str!("../docs/rule_macro.md")]
+#[macro_export]
+macro_rules! rule {
+  () => {};
+  ( @premises
+    [ $( $head:tt )* ] :
+    [ $( $premises:tt )* ]
+    =>
+    $( $rest:tt )*
+  ) => {
+    $crate::proof_list! {
+      [ $crate::rule ]
+      [ @conclusion [ $( $head )* ] : [ $( $premises )* ] => ]
+      []
+      $( $rest )*
+    }
+  };
+  ( @conclusion
+    [
+      $rule:ident
+      [ $( $rule_lt:lifetime ),* ]
+      [ $( $rule_param:ident ),* ]
+      ( $( $var:ident : $vartype:ty ),* )
+    ] :
+    [ $(
+      {
+        $premise:ident
+        [ $( $premise_lt:lifetime ),* ]
+        [ $( $premise_param:ty ),* ]
+        ( $( $premise_arg:ident ),* )
+      }
+    )+ ]
+    =>
+    [
+      {
+        $conclusion:ident
+        [ $( $conclusion_lt:lifetime ),* ]
+        [ $( $conclusion_param:ty ),* ]
+        ( $( $conclusion_arg:ident ),* )
+      }
+    ]
+    $( where [ $( $where:tt )* ] )?;
+    $( $rest:tt )*
+  ) => {
+    $crate::macros::paste! {
+      pub fn [< $rule:snake >]
+      <
+        $( $rule_lt, )*
+        $( $rule_param, )*
+        $( [< $var:camel Val >] : $crate::HasType<$vartype> ),*
+      >
+      (
+        $(
+          _ : & [< $premise:camel >]
+          <
+            $( $premise_lt, )*
+            $( $premise_param, )*
+            $( [< $premise_arg:camel Val >] ),*
+          >,
+        )+
+      ) ->
+        [< $conclusion:camel >]
+        <
+          $( $conclusion_lt, )*
+          $( $conclusion_param, )*
+          $( [< $conclusion_arg:camel Val >] ),*
+        >
+      where
+        $( $( $where )* )?
+      {
+        [< $conclusion:camel >] ( ::core::marker::PhantomData )
+      }
+    }
+
+    $crate::rule! { $( $rest )* }
+  };
+  ( @conclusion
+    [ $( $head:tt )* ] :
+    [ $( $premises:tt )* ]
+    =>
+    [ $( $conclusion:tt )* ]
+    where $( $rest:tt )*
+  ) => {
+    $crate::where_clause! {
+      [ $crate::rule ]
+      [
+        @conclusion
+        [ $( $head )* ] :
+        [ $( $premises )* ]
+        =>
+        [ $( $conclusion )* ]
+        where
+      ]
+      []
+      $( $rest )*
+    }
+  };
+  ( $rule:ident
+    $( <
+      $( $rule_lt:lifetime ),* $(,)?
+      $( $rule_param:ident ),* $(,)?
+    > )?
+    ( $( $var:ident : $vartype:ty ),* $(,)? ) :
+    $( $rest:tt )*
+  ) => {
+    $crate::proof_list! {
+      [ $crate::rule ]
+      [
+        @premises
+        [
+          $rule
+          [ $( $( $rule_lt ),* )? ]
+          [ $( $( $rule_param ),* )? ]
+          ( $( $var : $vartype ),* )
+        ] :
+      ]
+      []
+      $( $rest )*
+    }
+  };
+}
+
+#[doc = include_str!("../docs/refine_macro.md")]
+#[macro_export]
+macro_rules! refine {
+  ( $( #[$attr:meta] )*
+    $vis:vis enum $enum:ident {
+      $(
+        $( #[$variant_attr:meta] )*
+        $variant:ident
+        $( ( $( $tuple_type:ty ),* $(,)? ) )?
+        $( { $( $struct_field:ident : $struct_type:ty ),* $(,)? } )?
+        $( = $discriminant:expr )?
+      ),* $(,)?
+    }
+  ) => {
+    $( #[$attr] )*
+    $vis enum $enum {
+      $(
+        $( #[$variant_attr] )*
+        $variant
+        $( ( $( $tuple_type ),* ) )?
+        $( { $( $struct_field : $struct_type ),* } )?
+        $( = $discriminant )?
+      ),*
+    }
+
+    $crate::macros::paste! {
+      $vis mod [< $enum:snake >] {
+        #[allow(unused_imports)]
+        use super::*;
+
+        $(
+          pub struct $variant;
+        )*
+
+        $crate::proof! {
+          IsVariant<Variant>(value: super::$enum);
+        }
+
+        pub enum Refined<N: $crate::HasType<super::$enum>>
+        {
+          $(
+            $variant(IsVariant<$variant, N>),
+          )*
+        }
+
+        pub fn refine<N: $crate::HasType<super::$enum>>(
+          value: impl $crate::AsNamedRef<N, super::$enum>,
+        ) -> Refined<N>
+        {
+          match value.as_named_ref().value() {
+            $(
+              super::$enum::$variant { .. } => Refined::$variant(IsVariant::new()),
+            )*
+          }
+        }
+
+        $(
+          $crate::refine_accessor! {
+            $enum
+            $variant
+            $( ( $( $tuple_type ),* ) )?
+            $( { $( $struct_field : $struct_type ),* } )?
+          }
+        )*
+      }
+    }
+  };
+}
+
+/**
+ Generates the field accessor of a variant for [`refine!`], binding
+ the fields of tuple variants to names taken from a fixed pool.
+*/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! refine_accessor {
+  ( @bind
+    $enum:ident
+    $variant:ident
+    [ $( $field:ident : $type:ty ),* ]
+    [ $next_field:ident $( $pool:ident )* ]
+    $next_type:ty $( , $rest:ty )*
+  ) => {
+    $crate::refine_accessor! {
+      @bind
+      $enum
+      $variant
+      [ $( $field : $type, )* $next_field : $next_type ]
+      [ $( $pool )* ]
+      $( $rest ),*
+    }
+  };
+  ( @bind
+    $enum:ident
+    $variant:ident
+    [ $( $field:ident : $type:ty ),* ]
+    [ $( $pool:ident )* ]
+  ) => {
+    $crate::refine_accessor! {
+      @accessor
+      $enum
+      $variant
+      ( ( $( $field ),* ) )
+      [ $( $field : $type ),* ]
+    }
+  };
+  ( @accessor
+    $enum:ident
+    $variant:ident
+    ( $( $pattern:tt )* )
+    [ $field:ident : $type:ty ]
+  ) => {
+    $crate::refine_accessor! {
+      @function
+      $enum
+      $variant
+      ( $( $pattern )* )
+      ( &'a $type )
+      ( $field )
+    }
+  };
+  ( @accessor
+    $enum:ident
+    $variant:ident
+    ( $( $pattern:tt )* )
+    [ $( $field:ident : $type:ty ),* ]
+  ) => {
+    $crate::refine_accessor! {
+      @function
+      $enum
+      $variant
+      ( $( $pattern )* )
+      ( ( $( &'a $type ),* ) )
+      ( ( $( $field ),* ) )
+    }
+  };
+  ( @function
+    $enum:ident
+    $variant:ident
+    ( $( $pattern:tt )* )
+    ( $( $return_type:tt )* )
+    ( $( $fields:tt )* )
+  ) => {
+    $crate::macros::paste! {
+      #[allow(unreachable_patterns)]
+      pub fn [< $variant:snake >]<'a, N: $crate::HasType<super::$enum>>(
+        value: impl Into<$crate::NamedRef<'a, N, super::$enum>>,
+        _is_variant: &IsVariant<$variant, N>,
+      ) -> $( $return_type )*
+      {
+        match value.into().value() {
+          super::$enum::$variant $( $pattern )* => $( $fields )*,
+          _ => unreachable!(
+            "IsVariant proof was constructed for a different variant"
+          ),
+        }
+      }
+    }
+  };
+  ( $enum:ident $variant:ident ) => {};
+  ( $enum:ident $variant:ident () ) => {};
+  ( $enum:ident $variant:ident {} ) => {};
+  ( $enum:ident
+    $variant:ident
+    { $( $field:ident : $type:ty ),+ }
+  ) => {
+    $crate::refine_accessor! {
+      @accessor
+      $enum
+      $variant
+      ( { $( $field ),* } )
+      [ $( $field : $type ),* ]
+    }
+  };
+  ( $enum:ident
+    $variant:ident
+    ( $( $type:ty ),+ )
+  ) => {
+    $crate::refine_accessor! {
+      @bind
+      $enum
+      $variant
+      []
+      [
+        field0 field1 field2 field3 field4 field5
+        field6 field7 field8 field9 field10 field11
+      ]
+      $( $type ),*
+    }
+  };
+}
+
+#[doc = include_str!("../docs/project_macro.md")]
+#[macro_export]
+macro_rules! project {
+  ( $( #[$attr:meta] )*
+    $vis:vis struct $struct:ident {
+      $( $fields:tt )*
+    }
+  ) => {
+    $( #[$attr] )*
+    $vis struct $struct {
+      $( $fields )*
+    }
+
+    $crate::project_field! {
+      [ impl ]
+      $struct
+      $( $fields )*
+    }
+
+    $crate::macros::paste! {
+      $vis mod [< $struct:snake >] {
+        $crate::project_field! {
+          [ item ]
+          $struct
+          $( $fields )*
+        }
+      }
+    }
+  };
+}
+
+/**
+ Generates the items of each field for [`project!`]. In the `impl`
+ mode, the trait implementations are generated in the scope of the
+ struct, so that the field types are resolved there. In the `item`
+ mode, the marker and projection function are generated in the module
+ of the struct, with the visibility of the field adjusted to be
+ relative to the parent module. [`HasField`] is only implemented for
+ the public fields.
+
+ [`HasField`]: crate::proof::field::HasField
+*/
+#[doc(hidden)]
+#[macro_export]
+macro_rules! project_field {
+  ( [ $mode:ident ] $struct:ident ) => {};
+  ( [ $mode:ident ]
+    $struct:ident
+    $( #[$field_attr:meta] )*
+    pub ( $( $field_vis:tt )* ) $field:ident : $type:ty
+    $( , $( $rest:tt )* )?
+  ) => {
+    $crate::project_field! {
+      @restricted
+      [ $( $field_vis )* ]
+      [ $mode ]
+      $struct $field : $type
+    }
+
+    $crate::project_field! {
+      [ $mode ]
+      $struct
+      $( $( $rest )* )?
+    }
+  };
+  ( [ $mode:ident ]
+    $struct:ident
+    $( #[$field_attr:meta] )*
+    pub $field:ident : $type:ty
+    $( , $( $rest:tt )* )?
+  ) => {
+    $crate::project_field! {
+      @public
+      [ $mode ]
+      $struct $field : $type
+    }
+
+    $crate::project_field! {
+      [ $mode ]
+      $struct
+      $( $( $rest )* )?
+    }
+  };
+  ( [ $mode:ident ]
+    $struct:ident
+    $( #[$field_attr:meta] )*
+    $field:ident : $type:ty
+    $( , $( $rest:tt )* )?
+  ) => {
+    $crate::project_field! {
+      @private
+      [ pub(super) ]
+      [ $mode ]
+      $struct $field : $type
+    }
+
+    $crate::project_field! {
+      [ $mode ]
+      $struct
+      $( $( $rest )* )?
+    }
+  };
+  ( @restricted [ crate ] $( $rest:tt )* ) => {
+    $crate::project_field! { @private [ pub(crate) ] $( $rest )* }
+  };
+  ( @restricted [ self ] $( $rest:tt )* ) => {
+    $crate::project_field! { @private [ pub(super) ] $( $rest )* }
+  };
+  ( @restricted [ super ] $( $rest:tt )* ) => {
+    $crate::project_field! { @private [ pub(in super::super) ] $( $rest )* }
+  };
+  ( @restricted [ in crate $( :: $path:ident )* ] $( $rest:tt )* ) => {
+    $crate::project_field! {
+      @private [ pub(in crate $( :: $path )*) ] $( $rest )*
+    }
+  };
+  ( @restricted [ in self $( :: $path:ident )* ] $( $rest:tt )* ) => {
+    $crate::project_field! {
+      @private [ pub(in super $( :: $path )*) ] $( $rest )*
+    }
+  };
+  ( @restricted [ in super $( :: $path:ident )* ] $( $rest:tt )* ) => {
+    $crate::project_field! {
+      @private [ pub(in super::super $( :: $path )*) ] $( $rest )*
+    }
+  };
+  ( @public [ impl ] $struct:ident $field:ident : $type:ty ) => {
+    $crate::macros::paste! {
+      unsafe impl $crate::proof::field::HasField<
+        [< $struct:snake >]::[< $field:camel >]
+      > for $struct
+      {
+        type Type = $type;
+
+        fn field(&self) -> &$type
+        {
+          &self.$field
+        }
+      }
+    }
+  };
+  ( @public [ item ] $struct:ident $field:ident : $type:ty ) => {
+    $crate::macros::paste! {
+      pub struct [< $field:camel >];
+
+      pub fn $field<'a, N: $crate::HasType<super::$struct>>(
+        seed: impl $crate::Seed,
+        value: impl Into<$crate::NamedRef<'a, N, super::$struct>>,
+      ) -> $crate::sigma::Exists<
+        impl $crate::HasType<
+          &'a <super::$struct as $crate::proof::field::HasField<
+            [< $field:camel >],
+          >>::Type,
+        >,
+        &'a <super::$struct as $crate::proof::field::HasField<
+          [< $field:camel >],
+        >>::Type,
+        $crate::proof::field::FieldOfFamily<[< $field:camel >], N>,
+      >
+      {
+        $crate::proof::field::project(seed, value)
+      }
+    }
+  };
+  ( @private
+    [ $( $vis:tt )* ]
+    [ impl ]
+    $struct:ident $field:ident : $type:ty
+  ) => {
+    $crate::macros::paste! {
+      impl $crate::proof::field::FieldType<
+        [< $struct:snake >]::[< $field:camel >]
+      > for $struct
+      {
+        type Type = $type;
+      }
+    }
+  };
+  ( @private
+    [ $( $vis:tt )* ]
+    [ item ]
+    $struct:ident $field:ident : $type:ty
+  ) => {
+    $crate::macros::paste! {
+      $( $vis )* struct [< $field:camel >];
+
+      $( $vis )* fn $field<'a, N: $crate::HasType<super::$struct>>(
+        seed: impl $crate::Seed,
+        value: impl Into<$crate::NamedRef<'a, N, super::$struct>>,
+      ) -> $crate::sigma::Exists<
+        impl $crate::HasType<
+          &'a <super::$struct as $crate::proof::field::FieldType<
+            [< $field:camel >],
+          >>::Type,
+        >,
+        &'a <super::$struct as $crate::proof::field::FieldType<
+          [< $field:camel >],
+        >>::Type,
+        $crate::proof::field::FieldOfFamily<[< $field:camel >], N>,
+      >
+      {
+        // Safety: the accessor always returns the same field.
+        unsafe {
+          $crate::proof::field::project_with(seed, value, |value| {
+            &value.$field
+          })
         }
       }
     }
-  }
+  };
 }
@@ -1,5 +1,9 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 use core::marker::PhantomData;
 
+use crate::internal::Brand;
+
 /**
  A marker trait that is used to represent unique type in Rust.
  `mononym` guarantees that any two `impl Name` generated by
@@ -25,8 +29,12 @@ pub trait Name: Send + Sync + Sealed {}
  This trait is used as a type parameter inside [`Named`], so that the
  type `Named<impl HasType<T>, T>` also attaches the type information
  to the type-level name associated with the named value.
+
+ The type `T` can also be an unsized type such as `str`, `[T]` or
+ `dyn Trait`, for names of values that are stored behind a
+ [`NamedRef`], or behind a `NamedBox` with the `alloc` feature.
 */
-pub trait HasType<T>: Name {}
+pub trait HasType<T: ?Sized>: Name {}
 
 /**
  Represents a named value with a unique type-level name. `monoym`
@@ -49,9 +57,9 @@ pub struct Named<N: HasType<T>, T>(T, PhantomData<N>);
 */
 pub trait Sealed {}
 
-pub trait Seed: Sealed + Send + 'static
+pub trait Seed: Sealed + Send
 {
-  type Name<T>: HasType<T>;
+  type Name<T: ?Sized>: HasType<T>;
 
   type Next1: Seed;
 
@@ -63,6 +71,28 @@ pub trait Seed: Sealed + Send + 'static
     self,
     value: T,
   ) -> Named<Self::Name<T>, T>;
+
+  /**
+   Gives a fresh name to a borrowed value, which may be unsized.
+   This allows proofs to be about the borrowed value itself, such
+   as a `str`, regardless of the container the value is borrowed
+   from.
+  */
+  fn new_named_ref<T: ?Sized>(
+    self,
+    value: &T,
+  ) -> NamedRef<'_, Self::Name<T>, T>;
+
+  /**
+   Gives a fresh name to a boxed value, which may be unsized. The
+   name has the type of the value inside the box, such as `[T]` for
+   a `Box<[T]>`.
+  */
+  #[cfg(feature = "alloc")]
+  fn new_named_box<T: ?Sized>(
+    self,
+    value: Box<T>,
+  ) -> NamedBox<Self::Name<T>, T>;
 }
 
 impl<'a> Life<'a>
@@ -70,7 +100,10 @@ impl<'a> Life<'a>
   /**
    Turns a unique lifetime proxy to an existential [`Seed`].
    Since all `Life<'a>` must be unique via [`with_seed`],
-   the returned `impl Seed` is also always unique.
+   the returned `impl Seed` is also always unique. The seed and
+   the names it gives are branded with the lifetime `'a`, so
+   seeds from nested calls to `with_seed` are different types,
+   even if they are replicated in the same way.
 
    The existential associated types `Next1` and Next2` are
    also treated as different types by Rust, even though they
@@ -86,37 +119,65 @@ impl<'a> Life<'a>
    }
    ```
   */
-  pub fn into_seed(self) -> impl Seed
-  {
-    struct SomeName;
-    struct SomeSeed;
-
-    impl Sealed for SomeName {}
-    impl Name for SomeName {}
-    impl<T> HasType<T> for SomeName {}
-
-    impl Sealed for SomeSeed {}
-    impl Seed for SomeSeed
-    {
-      type Name<T> = SomeName;
-      type Next1 = SomeSeed;
-      type Next2 = SomeSeed;
-
-      fn replicate<'a>(self) -> (Self::Next1, Self::Next2)
-      {
-        (SomeSeed, SomeSeed)
-      }
-
-      fn new_named<T>(
-        self,
-        value: T,
-      ) -> Named<Self::Name<T>, T>
-      {
-        Named(value, PhantomData)
-      }
-    }
-
-    SomeSeed
+  pub fn into_seed(self) -> impl Seed + 'a
+  {
+    LifeSeed(PhantomData)
+  }
+}
+
+/**
+ The name type given by the seed of [`Life<'name>`](Life). The name is
+ branded with the unique lifetime `'name`, so that the names given by
+ the seeds of two different calls to [`with_seed`] are always
+ different types.
+*/
+struct LifeName<'name>(Brand<'name>);
+
+/**
+ The seed type returned by [`Life::into_seed`], which is branded with
+ the unique lifetime `'name` in the same way as [`LifeName`].
+*/
+struct LifeSeed<'name>(Brand<'name>);
+
+impl<'name> Sealed for LifeName<'name> {}
+impl<'name> Name for LifeName<'name> {}
+impl<'name, T: ?Sized> HasType<T> for LifeName<'name> {}
+
+impl<'name> Sealed for LifeSeed<'name> {}
+impl<'name> Seed for LifeSeed<'name>
+{
+  type Name<T: ?Sized> = LifeName<'name>;
+  type Next1 = LifeSeed<'name>;
+  type Next2 = LifeSeed<'name>;
+
+  fn replicate(self) -> (Self::Next1, Self::Next2)
+  {
+    (LifeSeed(PhantomData), LifeSeed(PhantomData))
+  }
+
+  fn new_named<T>(
+    self,
+    value: T,
+  ) -> Named<Self::Name<T>, T>
+  {
+    Named(value, PhantomData)
+  }
+
+  fn new_named_ref<T: ?Sized>(
+    self,
+    value: &T,
+  ) -> NamedRef<'_, Self::Name<T>, T>
+  {
+    NamedRef(value, PhantomData)
+  }
+
+  #[cfg(feature = "alloc")]
+  fn new_named_box<T: ?Sized>(
+    self,
+    value: Box<T>,
+  ) -> NamedBox<Self::Name<T>, T>
+  {
+    NamedBox(value, PhantomData)
   }
 }
 
@@ -224,3 +285,223 @@ impl<N: HasType<T>, T> Named<N, T>
     self.0
   }
 }
+
+/**
+ A borrowed reference to a [`Named`] value that keeps the original
+ name `N`. Since the name is unchanged, all proofs about `N` still
+ apply to the borrowed value. Unlike `&Named<N, T>`, a `NamedRef` can
+ be obtained from any type implementing [`AsNamedRef`], and
+ [`NamedRef::value`] returns a reference with the full lifetime `'a`.
+
+ A `NamedRef` can only be created by borrowing an existing named value,
+ so there can still be no two values of the same name.
+
+ ```rust
+ # use mononym::*;
+ fn first<'a, N: HasType<Vec<u32>>>(
+   list: NamedRef<'a, N, Vec<u32>>
+ ) -> Option<&'a u32>
+ {
+   list.value().first()
+ }
+
+ with_seed(|life| {
+   let list = life.into_seed().new_named(vec![1, 2, 3]);
+   let list_ref = list.as_named_ref();
+
+   assert_eq!(first(list_ref), Some(&1));
+   assert_eq!(first(list_ref.as_named_ref()), Some(&1));
+ });
+ ```
+*/
+pub struct NamedRef<'a, N: HasType<T>, T: ?Sized>(&'a T, PhantomData<N>);
+
+/**
+ Implemented by [`Named`], [`NamedRef`] and references to them, so
+ that functions can accept either owned or borrowed named values
+ with the name `N`. The functions in [`proof`](mod@crate::proof) that
+ check for proofs, as well as the check functions generated by
+ [`proof!`](crate::proof!), accept any `impl AsNamedRef`:
+
+ ```rust
+ # use mononym::{*, proof::ord::check_less_than};
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let small = seed1.new_named(1);
+   let large = seed2.new_named(2);
+
+   assert!(check_less_than(&small, large.as_named_ref()).is_some());
+ });
+ ```
+
+ The functions that return references borrowed from a named value,
+ such as [`non_empty::first`](crate::proof::non_empty::first) and
+ [`field::project`](crate::proof::field::project), instead accept any
+ `impl Into<NamedRef<'a, N, T>>`, so that the returned references can
+ live as long as the original named value. This is implemented by
+ `&Named`, `&NamedBox` and `NamedRef` itself.
+
+ Named values are still taken by value by the functions that consume
+ them, such as [`pair::split`](crate::proof::pair::split), and are
+ given by reference to the closures passed to
+ [`Exists`](crate::sigma::Exists).
+*/
+pub trait AsNamedRef<N: HasType<T>, T: ?Sized>
+{
+  fn as_named_ref(&self) -> NamedRef<'_, N, T>;
+}
+
+impl<'a, N: HasType<T>, T: ?Sized> NamedRef<'a, N, T>
+{
+  /**
+   Get a reference to the underlying value of the borrowed named
+   value, which lives as long as the original named value.
+  */
+  pub fn value(&self) -> &'a T
+  {
+    self.0
+  }
+}
+
+impl<'a, N: HasType<T>, T: ?Sized> Clone for NamedRef<'a, N, T>
+{
+  fn clone(&self) -> Self
+  {
+    *self
+  }
+}
+
+impl<'a, N: HasType<T>, T: ?Sized> Copy for NamedRef<'a, N, T> {}
+
+impl<N: HasType<T>, T> AsNamedRef<N, T> for Named<N, T>
+{
+  fn as_named_ref(&self) -> NamedRef<'_, N, T>
+  {
+    NamedRef(&self.0, PhantomData)
+  }
+}
+
+impl<'a, N: HasType<T>, T: ?Sized> AsNamedRef<N, T> for NamedRef<'a, N, T>
+{
+  fn as_named_ref(&self) -> NamedRef<'_, N, T>
+  {
+    *self
+  }
+}
+
+impl<N: HasType<T>, T: ?Sized, A: AsNamedRef<N, T> + ?Sized> AsNamedRef<N, T>
+  for &A
+{
+  fn as_named_ref(&self) -> NamedRef<'_, N, T>
+  {
+    (**self).as_named_ref()
+  }
+}
+
+impl<'a, N: HasType<T>, T> From<&'a Named<N, T>> for NamedRef<'a, N, T>
+{
+  fn from(named: &'a Named<N, T>) -> Self
+  {
+    NamedRef(&named.0, PhantomData)
+  }
+}
+
+/**
+ A named value that is stored in a [`Box`], which allows the value to
+ be unsized, such as a `Box<str>` named with `HasType<str>`, or a
+ `Box<dyn Trait>` named with `HasType<dyn Trait>`. A `NamedBox` can
+ only be created with [`Seed::new_named_box`], and can be borrowed as
+ a [`NamedRef`] with the same name.
+
+ ```rust
+ # use mononym::*;
+ fn count_a<N: HasType<str>>(text: impl AsNamedRef<N, str>) -> usize
+ {
+   text.as_named_ref().value().matches('a').count()
+ }
+
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let owned = String::from("banana");
+
+   let boxed = seed1.new_named_box(Box::<str>::from("abc"));
+   let borrowed = seed2.new_named_ref(owned.as_str());
+
+   assert_eq!(count_a(&boxed), 1);
+   assert_eq!(count_a(borrowed), 3);
+ });
+ ```
+
+ Since the name only refers to the unsized value, proofs about the
+ contents of a `str` or a `[T]` do not depend on how the value is
+ stored, and apply the same way to a `NamedRef` and a `NamedBox`:
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::{index::*, non_empty::*};
+ proof! {
+   IsAscii(text: str) if text.is_ascii();
+ }
+
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let (seed3, seed4) = seed3.replicate();
+   let owned = String::from("héllo");
+   let numbers = [1, 2, 3];
+
+   let borrowed = seed1.new_named_ref(owned.as_str());
+   let boxed = seed2.new_named_box(Box::<str>::from("hello"));
+
+   assert!(check_is_ascii(borrowed).is_none());
+   let _is_ascii: IsAscii<_> = check_is_ascii(&boxed).unwrap();
+   assert!(check_non_empty(&boxed).is_some());
+
+   let slice = seed3.new_named_ref(&numbers[..]);
+   let index = seed4.new_named(2);
+   let in_bounds = check_in_bounds(slice, &index).unwrap();
+   assert_eq!(*get(slice, &index, &in_bounds), 3);
+ });
+ ```
+*/
+#[cfg(feature = "alloc")]
+pub struct NamedBox<N: HasType<T>, T: ?Sized>(Box<T>, PhantomData<N>);
+
+#[cfg(feature = "alloc")]
+impl<N: HasType<T>, T: ?Sized> NamedBox<N, T>
+{
+  /**
+   Get a reference to the underlying value of the named box.
+  */
+  pub fn value(&self) -> &T
+  {
+    &self.0
+  }
+
+  /**
+   Consume the named box and turn it back into the underlying box.
+  */
+  pub fn into_box(self) -> Box<T>
+  {
+    self.0
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<N: HasType<T>, T: ?Sized> AsNamedRef<N, T> for NamedBox<N, T>
+{
+  fn as_named_ref(&self) -> NamedRef<'_, N, T>
+  {
+    NamedRef(&self.0, PhantomData)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, N: HasType<T>, T: ?Sized> From<&'a NamedBox<N, T>>
+  for NamedRef<'a, N, T>
+{
+  fn from(named: &'a NamedBox<N, T>) -> Self
+  {
+    NamedRef(&named.0, PhantomData)
+  }
+}
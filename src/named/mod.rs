@@ -1,10 +1,14 @@
 mod internal;
 
+#[cfg(feature = "alloc")]
+pub use internal::NamedBox;
 pub use internal::{
   with_seed,
+  AsNamedRef,
   HasType,
   Life,
   Name,
   Named,
+  NamedRef,
   Seed,
 };
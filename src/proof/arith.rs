@@ -0,0 +1,589 @@
+/*!
+ Checked arithmetic on named integers, returning the named results
+ together with proofs of how they relate to the operands.
+*/
+
+use crate::{
+  named::*,
+  proof::{
+    num::{
+      Integer,
+      NonNegative,
+      Positive,
+    },
+    ord::{
+      new_less_than,
+      new_less_than_eq,
+      LessThan,
+      LessThanEq,
+    },
+  },
+};
+
+/**
+ Marker type for the addition operation in [`NoOverflow`].
+*/
+pub struct Add;
+
+/**
+ Marker type for the subtraction operation in [`NoOverflow`].
+*/
+pub struct Sub;
+
+/**
+ Marker type for the multiplication operation in [`NoOverflow`].
+*/
+pub struct Mul;
+
+crate::proof! {
+  IsSum<T>(sum: T, first: T, second: T);
+  IsDifference<T>(difference: T, first: T, second: T);
+  IsProduct<T>(product: T, first: T, second: T);
+  NoOverflow<Op, T>(first: T, second: T);
+}
+
+pub struct ExistSum<
+  T,
+  SumVal: HasType<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+> {
+  pub sum: Named<SumVal, T>,
+  pub is_sum: IsSum<T, SumVal, FirstVal, SecondVal>,
+}
+
+pub struct ExistDifference<
+  T,
+  DifferenceVal: HasType<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+> {
+  pub difference: Named<DifferenceVal, T>,
+  pub is_difference: IsDifference<T, DifferenceVal, FirstVal, SecondVal>,
+}
+
+pub struct ExistProduct<
+  T,
+  ProductVal: HasType<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+> {
+  pub product: Named<ProductVal, T>,
+  pub is_product: IsProduct<T, ProductVal, FirstVal, SecondVal>,
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn new_is_sum<
+  T: Integer,
+  SumVal: HasType<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>() -> IsSum<T, SumVal, FirstVal, SecondVal>
+{
+  IsSum::new()
+}
+
+/**
+ Checks that adding two named integers does not overflow.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::arith::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let max = seed1.new_named(u8::MAX);
+   let zero = seed2.new_named(0_u8);
+   let one = seed3.new_named(1_u8);
+
+   assert!(check_add_no_overflow(&max, &zero).is_some());
+   assert!(check_add_no_overflow(&max, &one).is_none());
+ });
+ ```
+*/
+pub fn check_add_no_overflow<
+  T: Integer,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Option<NoOverflow<Add, T, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  first
+    .value()
+    .checked_add(*second.value())
+    .map(|_| NoOverflow::new())
+}
+
+/**
+ Checks that subtracting two named integers does not overflow.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::arith::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let min = seed1.new_named(i32::MIN);
+   let one = seed2.new_named(1);
+   let minus_one = seed3.new_named(-1);
+
+   assert!(check_sub_no_overflow(&min, &minus_one).is_some());
+   assert!(check_sub_no_overflow(&min, &one).is_none());
+ });
+ ```
+*/
+pub fn check_sub_no_overflow<
+  T: Integer,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Option<NoOverflow<Sub, T, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  first
+    .value()
+    .checked_sub(*second.value())
+    .map(|_| NoOverflow::new())
+}
+
+/**
+ Checks that multiplying two named integers does not overflow.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::arith::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let min = seed1.new_named(i64::MIN);
+   let one = seed2.new_named(1);
+   let minus_one = seed3.new_named(-1);
+
+   assert!(check_mul_no_overflow(&min, &one).is_some());
+   assert!(check_mul_no_overflow(&min, &minus_one).is_none());
+ });
+ ```
+*/
+pub fn check_mul_no_overflow<
+  T: Integer,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Option<NoOverflow<Mul, T, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  first
+    .value()
+    .checked_mul(*second.value())
+    .map(|_| NoOverflow::new())
+}
+
+/**
+ Adds two named integers, with the proof that the addition does not
+ overflow making the operation infallible.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::arith::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let x = seed1.new_named(2_u64);
+   let y = seed2.new_named(3_u64);
+
+   let no_overflow =
+     check_add_no_overflow(&x, &y).expect("2 + 3 should not overflow");
+
+   let ExistSum { sum, is_sum } = add(seed3, &x, &y, &no_overflow);
+   let _is_sum: IsSum<u64, _, _, _> = sum_commutative(&is_sum);
+
+   assert_eq!(*sum.value(), 5);
+ });
+ ```
+*/
+pub fn add<S: Seed, T: Integer, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  seed: S,
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+  _no_overflow: &NoOverflow<Add, T, FirstVal, SecondVal>,
+) -> ExistSum<T, S::Name<T>, FirstVal, SecondVal>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  match first.value().checked_add(*second.value()) {
+    Some(sum) => ExistSum {
+      sum: seed.new_named(sum),
+      is_sum: IsSum::new(),
+    },
+    None => unreachable!("NoOverflow proof was constructed for an overflow"),
+  }
+}
+
+/**
+ Subtracts two named integers, with the proof that the subtraction
+ does not overflow.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::arith::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let x = seed1.new_named(-2_i32);
+   let y = seed2.new_named(3_i32);
+
+   let no_overflow =
+     check_sub_no_overflow(&x, &y).expect("-2 - 3 should not overflow");
+
+   let ExistDifference { difference, .. } = sub(seed3, &x, &y, &no_overflow);
+
+   assert_eq!(*difference.value(), -5);
+ });
+ ```
+*/
+pub fn sub<S: Seed, T: Integer, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  seed: S,
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+  _no_overflow: &NoOverflow<Sub, T, FirstVal, SecondVal>,
+) -> ExistDifference<T, S::Name<T>, FirstVal, SecondVal>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  match first.value().checked_sub(*second.value()) {
+    Some(difference) => ExistDifference {
+      difference: seed.new_named(difference),
+      is_difference: IsDifference::new(),
+    },
+    None => unreachable!("NoOverflow proof was constructed for an overflow"),
+  }
+}
+
+/**
+ Multiplies two named integers, with the proof that the
+ multiplication does not overflow.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::arith::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let x = seed1.new_named(-4_i16);
+   let y = seed2.new_named(5_i16);
+
+   let no_overflow =
+     check_mul_no_overflow(&x, &y).expect("-4 * 5 should not overflow");
+
+   let ExistProduct { product, .. } = mul(seed3, &x, &y, &no_overflow);
+
+   assert_eq!(*product.value(), -20);
+ });
+ ```
+*/
+pub fn mul<S: Seed, T: Integer, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  seed: S,
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+  _no_overflow: &NoOverflow<Mul, T, FirstVal, SecondVal>,
+) -> ExistProduct<T, S::Name<T>, FirstVal, SecondVal>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  match first.value().checked_mul(*second.value()) {
+    Some(product) => ExistProduct {
+      product: seed.new_named(product),
+      is_product: IsProduct::new(),
+    },
+    None => unreachable!("NoOverflow proof was constructed for an overflow"),
+  }
+}
+
+/**
+ Adds two named integers, returning `None` if the addition
+ overflows.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::arith::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let (seed3, seed4) = seed3.replicate();
+   let (seed4, seed5) = seed4.replicate();
+   let max = seed1.new_named(u32::MAX);
+   let one = seed2.new_named(1);
+   let zero = seed3.new_named(0);
+
+   assert!(checked_add(seed4, &max, &one).is_none());
+
+   let ExistSum { sum, .. } = checked_add(seed5, &max, &zero).unwrap();
+   assert_eq!(*sum.value(), u32::MAX);
+ });
+ ```
+*/
+pub fn checked_add<
+  S: Seed,
+  T: Integer,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  seed: S,
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Option<ExistSum<T, S::Name<T>, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  first
+    .value()
+    .checked_add(*second.value())
+    .map(|sum| ExistSum {
+      sum: seed.new_named(sum),
+      is_sum: IsSum::new(),
+    })
+}
+
+/**
+ Subtracts two named integers, returning `None` if the subtraction
+ overflows.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::arith::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let (seed3, seed4) = seed3.replicate();
+   let zero = seed1.new_named(0_u64);
+   let one = seed2.new_named(1_u64);
+
+   assert!(checked_sub(seed3, &zero, &one).is_none());
+
+   let ExistDifference { difference, .. } =
+     checked_sub(seed4, &one, &zero).unwrap();
+   assert_eq!(*difference.value(), 1);
+ });
+ ```
+*/
+pub fn checked_sub<
+  S: Seed,
+  T: Integer,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  seed: S,
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Option<ExistDifference<T, S::Name<T>, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  first
+    .value()
+    .checked_sub(*second.value())
+    .map(|difference| ExistDifference {
+      difference: seed.new_named(difference),
+      is_difference: IsDifference::new(),
+    })
+}
+
+/**
+ Multiplies two named integers, returning `None` if the
+ multiplication overflows.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::arith::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let (seed3, seed4) = seed3.replicate();
+   let (seed4, seed5) = seed4.replicate();
+   let max = seed1.new_named(i8::MAX);
+   let two = seed2.new_named(2_i8);
+   let minus_one = seed3.new_named(-1_i8);
+
+   assert!(checked_mul(seed4, &max, &two).is_none());
+
+   let ExistProduct { product, .. } =
+     checked_mul(seed5, &max, &minus_one).unwrap();
+   assert_eq!(*product.value(), -i8::MAX);
+ });
+ ```
+*/
+pub fn checked_mul<
+  S: Seed,
+  T: Integer,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  seed: S,
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Option<ExistProduct<T, S::Name<T>, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  first
+    .value()
+    .checked_mul(*second.value())
+    .map(|product| ExistProduct {
+      product: seed.new_named(product),
+      is_product: IsProduct::new(),
+    })
+}
+
+pub fn sum_commutative<
+  T: Integer,
+  SumVal: HasType<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _is_sum: &IsSum<T, SumVal, FirstVal, SecondVal>
+) -> IsSum<T, SumVal, SecondVal, FirstVal>
+{
+  IsSum::new()
+}
+
+pub fn product_commutative<
+  T: Integer,
+  ProductVal: HasType<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _is_product: &IsProduct<T, ProductVal, FirstVal, SecondVal>
+) -> IsProduct<T, ProductVal, SecondVal, FirstVal>
+{
+  IsProduct::new()
+}
+
+pub fn add_no_overflow_commutative<
+  T: Integer,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _no_overflow: &NoOverflow<Add, T, FirstVal, SecondVal>
+) -> NoOverflow<Add, T, SecondVal, FirstVal>
+{
+  NoOverflow::new()
+}
+
+pub fn mul_no_overflow_commutative<
+  T: Integer,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _no_overflow: &NoOverflow<Mul, T, FirstVal, SecondVal>
+) -> NoOverflow<Mul, T, SecondVal, FirstVal>
+{
+  NoOverflow::new()
+}
+
+/**
+ If `sum = first + second`, then `first = sum - second`.
+*/
+pub fn sum_into_difference<
+  T: Integer,
+  SumVal: HasType<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _is_sum: &IsSum<T, SumVal, FirstVal, SecondVal>
+) -> IsDifference<T, FirstVal, SumVal, SecondVal>
+{
+  IsDifference::new()
+}
+
+/**
+ If `difference = first - second`, then `first = difference + second`.
+*/
+pub fn difference_into_sum<
+  T: Integer,
+  DifferenceVal: HasType<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _is_difference: &IsDifference<T, DifferenceVal, FirstVal, SecondVal>
+) -> IsSum<T, FirstVal, DifferenceVal, SecondVal>
+{
+  IsSum::new()
+}
+
+/**
+ Addition is monotonic: if `first1 <= first2`, then
+ `first1 + second <= first2 + second`.
+*/
+pub fn sum_monotonic<
+  T: Integer,
+  Sum1Val: HasType<T>,
+  Sum2Val: HasType<T>,
+  First1Val: HasType<T>,
+  First2Val: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _first_lte: &LessThanEq<T, First1Val, First2Val>,
+  _is_sum1: &IsSum<T, Sum1Val, First1Val, SecondVal>,
+  _is_sum2: &IsSum<T, Sum2Val, First2Val, SecondVal>,
+) -> LessThanEq<T, Sum1Val, Sum2Val>
+{
+  new_less_than_eq()
+}
+
+/**
+ Adding a non-negative number never decreases the original number.
+*/
+pub fn sum_increasing<
+  T: Integer,
+  SumVal: HasType<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _is_sum: &IsSum<T, SumVal, FirstVal, SecondVal>,
+  _second_non_negative: &NonNegative<T, SecondVal>,
+) -> LessThanEq<T, FirstVal, SumVal>
+{
+  new_less_than_eq()
+}
+
+/**
+ Adding a positive number always increases the original number.
+*/
+pub fn sum_strictly_increasing<
+  T: Integer,
+  SumVal: HasType<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _is_sum: &IsSum<T, SumVal, FirstVal, SecondVal>,
+  _second_positive: &Positive<T, SecondVal>,
+) -> LessThan<T, FirstVal, SumVal>
+{
+  new_less_than()
+}
+
+/**
+ Subtracting a non-negative number never increases the original number.
+*/
+pub fn difference_decreasing<
+  T: Integer,
+  DifferenceVal: HasType<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _is_difference: &IsDifference<T, DifferenceVal, FirstVal, SecondVal>,
+  _second_non_negative: &NonNegative<T, SecondVal>,
+) -> LessThanEq<T, DifferenceVal, FirstVal>
+{
+  new_less_than_eq()
+}
@@ -1,14 +1,94 @@
-use crate::named::*;
+use crate::{
+  named::*,
+  proof::logic::Decision,
+  sigma::ProofFamily,
+};
 
 crate::proof! {
-  IsEqual<T>(first: T, second: T);
+  IsEqual<T>(first: T, second: T) where T: ?Sized else NotEqual;
 }
 
-pub fn check_equal<T: Eq, FirstVal: HasType<T>, SecondVal: HasType<T>>(
-  first: &Named<FirstVal, T>,
-  second: &Named<SecondVal, T>,
+/**
+ A [`ProofFamily`] that can be used by [`transport`] to rewrite a
+ proof about one name into the same proof about another name,
+ without requiring access to the private constructor of the proof
+ type.
+
+ The [`proof!`](crate::proof!) macro generates a [`ProofFamily`] for
+ each typed argument of a proof, named after the proof and the
+ argument. For example, the proof `LessThanEq(x: u32, y: u32)` comes
+ with the family `LessThanEqAtX<YVal>`, which selects the position of
+ `x`, and `LessThanEqAtY<XVal>`, which selects the position of `y`.
+ The families do not implement `ValueFamily` by default, since only
+ the author of a proof can tell whether it is preserved by `Eq`. It
+ has to be implemented by hand, either for a generated family or for
+ a family of another proof type:
+
+ ```rust
+ # use mononym::*;
+ # use mononym::{proof::equal::ValueFamily, sigma::ProofFamily};
+ pub struct IsZero<NumVal>(core::marker::PhantomData<NumVal>);
+
+ pub struct IsZeroFamily;
+
+ impl ProofFamily<u32> for IsZeroFamily
+ {
+   type Proof<N: HasType<u32>> = IsZero<N>;
+ }
+
+ unsafe impl ValueFamily<u32> for IsZeroFamily {}
+ ```
+
+ Among the proofs provided by `mononym`, only the families of proofs
+ about `usize` values that memory safety relies on, such as the
+ index of [`InBounds`](crate::proof::index::InBounds) and the size of
+ [`ListHasSize`](crate::proof::list::ListHasSize), implement
+ `ValueFamily`. Proofs such as
+ [`HasKey`](crate::proof::map::HasKey) depend on the `Ord` of the key
+ type, which is not required to agree with `Eq`, so the following
+ code fails to compile:
+
+ ```rust,compile_fail
+ # use mononym::*;
+ # use mononym::proof::{equal::*, map::*};
+ # use std::collections::BTreeMap;
+ fn rewrite<
+   MapVal: HasType<BTreeMap<u32, u32>>,
+   FirstVal: HasType<u32>,
+   SecondVal: HasType<u32>,
+ >(
+   has_key: HasKey<u32, u32, MapVal, FirstVal>,
+   is_equal: &IsEqual<u32, FirstVal, SecondVal>,
+ ) -> HasKey<u32, u32, MapVal, SecondVal>
+ {
+   transport::<_, HasKeyAtKey<u32, u32, MapVal>, _, _>(is_equal, has_key)
+ }
+ ```
+
+ # Safety
+
+ Implementors must ensure that `Proof<N>` is a zero-sized proof type
+ whose validity is preserved under equality of the named values, as
+ given by the [`Eq`] implementation of `T`. That is, if the proof is
+ valid for a value named by `N`, it must also be valid for any other
+ value that is equal to it according to `Eq`, since this is all that
+ [`IsEqual`] witnesses. In particular, `Proof<N>` must not be a type
+ such as [`Named`], which relies on the uniqueness of `N` rather than
+ on the underlying value.
+*/
+pub unsafe trait ValueFamily<T: ?Sized>: ProofFamily<T> {}
+
+pub fn check_equal<
+  T: Eq + ?Sized,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
 ) -> Option<IsEqual<T, FirstVal, SecondVal>>
 {
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
   if first.value() == second.value() {
     Some(IsEqual::new())
   } else {
@@ -16,9 +96,126 @@ pub fn check_equal<T: Eq, FirstVal: HasType<T>, SecondVal: HasType<T>>(
   }
 }
 
-pub fn equal_commutative<T: Eq, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+pub(crate) fn new_is_equal<
+  T: Eq + ?Sized,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>() -> IsEqual<T, FirstVal, SecondVal>
+{
+  IsEqual::new()
+}
+
+pub fn decide_equal<
+  T: Eq + ?Sized,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Decision<IsEqual<T, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  if first.value() == second.value() {
+    Decision::Proved(IsEqual::new())
+  } else {
+    Decision::Refuted(IsEqual::refute())
+  }
+}
+
+/**
+ Any named value is equal to itself.
+*/
+pub fn refl<T: Eq + ?Sized, Val: HasType<T>>(
+  _value: impl AsNamedRef<Val, T>
+) -> IsEqual<T, Val, Val>
+{
+  IsEqual::new()
+}
+
+pub fn equal_commutative<
+  T: Eq + ?Sized,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
   _is_equal: IsEqual<T, FirstVal, SecondVal>
 ) -> IsEqual<T, SecondVal, FirstVal>
 {
   IsEqual::new()
 }
+
+pub fn not_equal_commutative<
+  T: Eq + ?Sized,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _not_equal: &NotEqual<T, FirstVal, SecondVal>
+) -> NotEqual<T, SecondVal, FirstVal>
+{
+  NotEqual::new()
+}
+
+pub fn equal_transitive<
+  T: Eq + ?Sized,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+  ThirdVal: HasType<T>,
+>(
+  _first_second: IsEqual<T, FirstVal, SecondVal>,
+  _second_third: IsEqual<T, SecondVal, ThirdVal>,
+) -> IsEqual<T, FirstVal, ThirdVal>
+{
+  IsEqual::new()
+}
+
+/**
+ Rewrites a proof mentioning the name `FirstVal` into the same proof
+ mentioning `SecondVal`, given that the two named values are equal.
+ The proof type being rewritten is selected by the
+ [`ValueFamily`] `F`, which usually needs to be given explicitly:
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::equal::*;
+ # proof! {
+ #   LessThanEq(x: u32, y: u32);
+ # }
+ #
+ // Safety: `u32` values that are equal according to `Eq` are the
+ // same value, so `x <= y` holds for both of them.
+ unsafe impl<YVal: HasType<u32>> ValueFamily<u32> for LessThanEqAtX<YVal> {}
+
+ fn rewrite<
+   XVal: HasType<u32>,
+   ZVal: HasType<u32>,
+   YVal: HasType<u32>,
+ >(
+   x_lte_y: LessThanEq<XVal, YVal>,
+   x_eq_z: &IsEqual<u32, XVal, ZVal>,
+ ) -> LessThanEq<ZVal, YVal>
+ {
+   transport::<_, LessThanEqAtX<YVal>, _, _>(x_eq_z, x_lte_y)
+ }
+ ```
+*/
+pub fn transport<
+  T: Eq + ?Sized,
+  F: ValueFamily<T>,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _is_equal: &IsEqual<T, FirstVal, SecondVal>,
+  proof: F::Proof<FirstVal>,
+) -> F::Proof<SecondVal>
+{
+  const {
+    assert!(core::mem::size_of::<F::Proof<FirstVal>>() == 0);
+    assert!(core::mem::size_of::<F::Proof<SecondVal>>() == 0);
+  }
+
+  // Safety: both proof types are zero-sized, and the contract of
+  // `ValueFamily` guarantees that the proof remains valid for values
+  // that are equal according to `Eq`, as witnessed by `IsEqual`.
+  let proof = core::mem::ManuallyDrop::new(proof);
+  unsafe { core::mem::transmute_copy(&*proof) }
+}
@@ -0,0 +1,159 @@
+/*!
+ Proofs that a named value is a field of a named struct, so that
+ the field can be reasoned about without comparing it again with
+ the struct. The [`HasField`] implementations and the per-field
+ projection functions are usually generated by
+ [`project!`](crate::project).
+*/
+
+use core::marker::PhantomData;
+
+use crate::{
+  named::*,
+  proof::equal::{
+    new_is_equal,
+    IsEqual,
+  },
+  sigma::{
+    Exists,
+    ProofFamily,
+  },
+};
+
+crate::proof! {
+  FieldOf<Field>(field, value);
+}
+
+/**
+ Implemented by a struct type `T` for each of its public fields, with
+ `Field` being a marker type for the field.
+
+ # Safety
+
+ The [`FieldOf`] proofs constructed by [`project`] assume that
+ `field` always returns a reference to the same field of the
+ value, so that two projections of the same field are equal as
+ given by [`field_of_unique`]. Implementors must ensure that
+ `field` returns the same reference every time it is called on
+ the same value, such as by returning a reference to a field.
+*/
+pub unsafe trait HasField<Field>
+{
+  type Type;
+
+  fn field(&self) -> &Self::Type;
+}
+
+/**
+ The type of the field `Field` of a struct, which is implemented by
+ [`project!`](crate::project) for the fields that are not public, in
+ place of [`HasField`].
+*/
+#[doc(hidden)]
+pub trait FieldType<Field>
+{
+  type Type;
+}
+
+/**
+ The [`ProofFamily`] of [`FieldOf`] proofs about the field `Field`
+ of the named value `ValueVal`.
+*/
+pub struct FieldOfFamily<Field, ValueVal>(PhantomData<(Field, ValueVal)>);
+
+impl<Field, ValueVal, T> ProofFamily<T> for FieldOfFamily<Field, ValueVal>
+{
+  type Proof<N: HasType<T>> = FieldOf<Field, N, ValueVal>;
+}
+
+/**
+ Borrows the field `Field` of a named value, and gives the reference
+ a fresh name together with the proof that it is the field of the
+ named value.
+
+ ```rust
+ # use mononym::{*, proof::field::*, sigma::Exists};
+ pub struct Author;
+
+ pub struct Post
+ {
+   author: String,
+ }
+
+ unsafe impl HasField<Author> for Post
+ {
+   type Type = String;
+
+   fn field(&self) -> &String
+   {
+     &self.author
+   }
+ }
+
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let post = seed1.new_named(Post {
+     author: "alice".to_string(),
+   });
+
+   let Exists {
+     value: author,
+     proof: _author_of_post,
+   } = project::<Author, _, _>(seed2, &post);
+
+   assert_eq!(*author.value(), "alice");
+ });
+ ```
+*/
+pub fn project<'a, Field, T: HasField<Field> + 'a, ValueVal: HasType<T>>(
+  seed: impl Seed,
+  value: impl Into<NamedRef<'a, ValueVal, T>>,
+) -> Exists<
+  impl HasType<&'a T::Type>,
+  &'a T::Type,
+  FieldOfFamily<Field, ValueVal>,
+>
+{
+  // Safety: the contract of `HasField` guarantees that `field` always
+  // returns the same field of the value.
+  unsafe { project_with(seed, value, HasField::field) }
+}
+
+/**
+ Projects the field `Field` of a named value with the accessor `get`.
+ This is used by [`project!`](crate::project) for the fields that are
+ not public, for which [`HasField`] is not implemented.
+
+ # Safety
+
+ The same as [`HasField`], `get` must return the same field of the
+ value for all projections with the marker `Field`.
+*/
+#[doc(hidden)]
+pub unsafe fn project_with<'a, Field, T: 'a, U, ValueVal: HasType<T>>(
+  seed: impl Seed,
+  value: impl Into<NamedRef<'a, ValueVal, T>>,
+  get: impl FnOnce(&'a T) -> &'a U,
+) -> Exists<impl HasType<&'a U>, &'a U, FieldOfFamily<Field, ValueVal>>
+{
+  Exists::new(seed, get(value.into().value()), |_| FieldOf::new())
+}
+
+/**
+ A field of a named value is unique, so any two projections of the
+ same field from the same named value are equal.
+*/
+pub fn field_of_unique<
+  'a,
+  Field,
+  U: Eq,
+  FirstVal: HasType<&'a U>,
+  SecondVal: HasType<&'a U>,
+  ValueVal,
+>(
+  _first: &FieldOf<Field, FirstVal, ValueVal>,
+  _second: &FieldOf<Field, SecondVal, ValueVal>,
+) -> IsEqual<&'a U, FirstVal, SecondVal>
+{
+  new_is_equal()
+}
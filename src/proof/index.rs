@@ -0,0 +1,202 @@
+/*!
+ Proofs that a named index is within the bounds of a named list,
+ so that the list can be indexed without a second bounds check.
+*/
+
+use crate::{
+  internal::Sealed,
+  named::*,
+  proof::{
+    equal::ValueFamily,
+    ord::LessThanEq,
+  },
+};
+
+crate::proof! {
+  InBounds<C>(index: usize, list: C) where C: ?Sized;
+}
+
+// Safety: indices that are equal according to the `Eq` of `usize` are
+// the same index. The family at `list` is not a `ValueFamily`, as the
+// memory safety of indexing would then rely on the `Eq` of the list
+// type to compare the lengths.
+unsafe impl<C: ?Sized, ListVal: HasType<C>> ValueFamily<usize>
+  for InBoundsAtIndex<C, ListVal>
+{
+}
+
+/**
+ Implemented by the list types that can be indexed with an
+ [`InBounds`] proof. This trait is sealed, as the unchecked
+ indexing in [`get`] relies on `as_slice` always returning
+ the same slice for the same list value.
+*/
+pub trait Indexable: Sealed
+{
+  type Elem;
+
+  fn as_slice(&self) -> &[Self::Elem];
+}
+
+impl<T, const N: usize> Indexable for [T; N]
+{
+  type Elem = T;
+
+  fn as_slice(&self) -> &[T]
+  {
+    self
+  }
+}
+
+impl<T> Indexable for [T]
+{
+  type Elem = T;
+
+  fn as_slice(&self) -> &[T]
+  {
+    self
+  }
+}
+
+impl<T> Indexable for &[T]
+{
+  type Elem = T;
+
+  fn as_slice(&self) -> &[T]
+  {
+    self
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Indexable for alloc::vec::Vec<T>
+{
+  type Elem = T;
+
+  fn as_slice(&self) -> &[T]
+  {
+    self
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Indexable for alloc::boxed::Box<[T]>
+{
+  type Elem = T;
+
+  fn as_slice(&self) -> &[T]
+  {
+    self
+  }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn new_in_bounds<
+  C: Indexable + ?Sized,
+  ListVal: HasType<C>,
+  IndexVal: HasType<usize>,
+>() -> InBounds<C, IndexVal, ListVal>
+{
+  InBounds::new()
+}
+
+pub fn check_in_bounds<
+  C: Indexable + ?Sized,
+  ListVal: HasType<C>,
+  IndexVal: HasType<usize>,
+>(
+  list: impl AsNamedRef<ListVal, C>,
+  index: impl AsNamedRef<IndexVal, usize>,
+) -> Option<InBounds<C, IndexVal, ListVal>>
+{
+  let list = list.as_named_ref();
+  let index = index.as_named_ref();
+  if *index.value() < list.value().as_slice().len() {
+    Some(InBounds::new())
+  } else {
+    None
+  }
+}
+
+/**
+ Gets a reference to the element at the named index, without
+ performing any bounds check.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::index::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let list = seed1.new_named([1, 2, 3]);
+   let index = seed2.new_named(2);
+
+   let in_bounds =
+     check_in_bounds(&list, &index).expect("index should be in bounds");
+
+   for _ in 0..3 {
+     assert_eq!(*get(&list, &index, &in_bounds), 3);
+   }
+ });
+ ```
+
+ Since the names from nested calls to [`with_seed`] are distinct, a
+ proof about a list named by an outer seed cannot be used with a list
+ named by an inner seed, even if the seeds are replicated the same
+ way. For example, the following code should fail to compile:
+
+ ```rust,compile_fail
+ # use mononym::*;
+ # use mononym::proof::index::*;
+ with_seed(|outer| {
+   let (seed1, seed2) = outer.into_seed().replicate();
+   let large = [0; 100];
+   let small = [0; 1];
+   let list = seed1.new_named(&large[..]);
+   let index = seed2.new_named(99);
+   let in_bounds = check_in_bounds(&list, &index).unwrap();
+
+   with_seed(|inner| {
+     let (seed3, _) = inner.into_seed().replicate();
+     let small_list = seed3.new_named(&small[..]);
+     get(&small_list, &index, &in_bounds); // error
+   });
+ });
+ ```
+*/
+pub fn get<
+  'a,
+  C: Indexable + ?Sized + 'a,
+  ListVal: HasType<C>,
+  IndexVal: HasType<usize>,
+>(
+  list: impl Into<NamedRef<'a, ListVal, C>>,
+  index: impl AsNamedRef<IndexVal, usize>,
+  _in_bounds: &InBounds<C, IndexVal, ListVal>,
+) -> &'a C::Elem
+{
+  let list = list.into();
+  let index = index.as_named_ref();
+  // Safety: `InBounds` can only be constructed by `check_in_bounds`,
+  // `sort::binary_search`, or by lemmas preserving it, which guarantee
+  // that the index is less than the length of the list. Since the
+  // names are branded by the lifetime of `with_seed`, the proof cannot
+  // be about a different list or index with the same name type.
+  unsafe { list.value().as_slice().get_unchecked(*index.value()) }
+}
+
+/**
+ Any index that is smaller than or equal to an in bounds index
+ is also in bounds.
+*/
+pub fn in_bounds_smaller<
+  C: Indexable + ?Sized,
+  ListVal: HasType<C>,
+  SmallerVal: HasType<usize>,
+  IndexVal: HasType<usize>,
+>(
+  _smaller: &LessThanEq<usize, SmallerVal, IndexVal>,
+  _in_bounds: &InBounds<C, IndexVal, ListVal>,
+) -> InBounds<C, SmallerVal, ListVal>
+{
+  InBounds::new()
+}
@@ -0,0 +1,311 @@
+/*!
+ Length-indexed operations on named lists, where the size of a list
+ is tracked as a named value with a [`ListHasSize`] proof.
+*/
+
+use alloc::vec::Vec;
+
+use crate::{
+  named::*,
+  proof::{
+    arith::{
+      new_is_sum,
+      IsSum,
+    },
+    equal::{
+      new_is_equal,
+      IsEqual,
+      ValueFamily,
+    },
+    index::{
+      new_in_bounds,
+      InBounds,
+    },
+    non_empty::{
+      new_non_empty,
+      NonEmpty,
+    },
+    num::Positive,
+    ord::LessThan,
+    sort::PermutationOf,
+  },
+};
+
+crate::exists! {
+  ExistSize(size: usize) => ListHasSize<T>(list: Vec<T>);
+}
+
+// Safety: sizes that are equal according to the `Eq` of `usize` are
+// the same size. The family at `list` is not a `ValueFamily`, as the
+// memory safety of indexing would then rely on the `Eq` of `Vec<T>`
+// to compare the lengths.
+unsafe impl<T, ListVal: HasType<Vec<T>>> ValueFamily<usize>
+  for ListHasSizeAtSize<T, ListVal>
+{
+}
+
+pub struct ExistZip<
+  T,
+  U,
+  SizeVal: HasType<usize>,
+  ListVal: HasType<Vec<(T, U)>>,
+> {
+  pub list: Named<ListVal, Vec<(T, U)>>,
+  pub list_has_size: ListHasSize<(T, U), SizeVal, ListVal>,
+}
+
+pub struct ExistConcat<
+  T,
+  SizeVal: HasType<usize>,
+  ListVal: HasType<Vec<T>>,
+  FirstSizeVal: HasType<usize>,
+  SecondSizeVal: HasType<usize>,
+> {
+  pub list: Named<ListVal, Vec<T>>,
+  pub size: Named<SizeVal, usize>,
+  pub list_has_size: ListHasSize<T, SizeVal, ListVal>,
+  pub is_sum: IsSum<usize, SizeVal, FirstSizeVal, SecondSizeVal>,
+}
+
+pub struct ExistMap<U, SizeVal: HasType<usize>, ListVal: HasType<Vec<U>>>
+{
+  pub list: Named<ListVal, Vec<U>>,
+  pub list_has_size: ListHasSize<U, SizeVal, ListVal>,
+}
+
+pub fn len<S: Seed, T, ListVal: HasType<Vec<T>>>(
+  seed: S,
+  list: impl AsNamedRef<ListVal, Vec<T>>,
+) -> ExistSize<S::Name<usize>, T, ListVal>
+{
+  let list = list.as_named_ref();
+  ExistSize {
+    size: seed.new_named(list.value().len()),
+    list_has_size: ListHasSize::new(),
+  }
+}
+
+/**
+ Zips two lists that are known to have the same size. Unlike
+ [`Iterator::zip`], no element is ever silently dropped, and the
+ zipped list has the same size as the original lists.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::{equal::check_equal, list::*};
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let (seed3, seed4) = seed3.replicate();
+   let (seed4, seed5) = seed4.replicate();
+
+   let names = seed1.new_named(vec!["a", "b"]);
+   let values = seed2.new_named(vec![1, 2]);
+
+   let names_size = len(seed3, &names);
+   let values_size = len(seed4, &values);
+
+   let same_size = check_equal(&names_size.size, &values_size.size)
+     .expect("lists should have the same size");
+
+   let zipped = zip(
+     seed5,
+     names,
+     values,
+     &names_size.list_has_size,
+     &values_size.list_has_size,
+     &same_size,
+   );
+
+   assert_eq!(zipped.list.value(), &vec![("a", 1), ("b", 2)]);
+ });
+ ```
+*/
+pub fn zip<
+  T,
+  U,
+  FirstListVal: HasType<Vec<T>>,
+  SecondListVal: HasType<Vec<U>>,
+  FirstSizeVal: HasType<usize>,
+  SecondSizeVal: HasType<usize>,
+>(
+  seed: impl Seed,
+  first: Named<FirstListVal, Vec<T>>,
+  second: Named<SecondListVal, Vec<U>>,
+  _first_has_size: &ListHasSize<T, FirstSizeVal, FirstListVal>,
+  _second_has_size: &ListHasSize<U, SecondSizeVal, SecondListVal>,
+  _same_size: &IsEqual<usize, FirstSizeVal, SecondSizeVal>,
+) -> ExistZip<T, U, FirstSizeVal, impl HasType<Vec<(T, U)>>>
+{
+  let list = first
+    .into_value()
+    .into_iter()
+    .zip(second.into_value())
+    .collect();
+
+  ExistZip {
+    list: seed.new_named(list),
+    list_has_size: ListHasSize::new(),
+  }
+}
+
+/**
+ Concatenates two lists, returning the new list together with
+ its size and the proof that it is the sum of the sizes of the
+ two lists.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::list::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let (seed3, seed4) = seed3.replicate();
+   let (seed4, seed5) = seed4.replicate();
+
+   let first = seed1.new_named(vec![1, 2]);
+   let second = seed2.new_named(vec![3, 4, 5]);
+
+   let first_size = len(seed3, &first);
+   let second_size = len(seed4, &second);
+
+   let ExistConcat { list, size, .. } = concat(
+     seed5,
+     first,
+     second,
+     &first_size.list_has_size,
+     &second_size.list_has_size,
+   );
+
+   assert_eq!(list.value(), &vec![1, 2, 3, 4, 5]);
+   assert_eq!(*size.value(), 5);
+ });
+ ```
+*/
+pub fn concat<
+  T,
+  FirstListVal: HasType<Vec<T>>,
+  SecondListVal: HasType<Vec<T>>,
+  FirstSizeVal: HasType<usize>,
+  SecondSizeVal: HasType<usize>,
+>(
+  seed: impl Seed,
+  first: Named<FirstListVal, Vec<T>>,
+  second: Named<SecondListVal, Vec<T>>,
+  _first_has_size: &ListHasSize<T, FirstSizeVal, FirstListVal>,
+  _second_has_size: &ListHasSize<T, SecondSizeVal, SecondListVal>,
+) -> ExistConcat<
+  T,
+  impl HasType<usize>,
+  impl HasType<Vec<T>>,
+  FirstSizeVal,
+  SecondSizeVal,
+>
+{
+  let (seed1, seed2) = seed.replicate();
+  let mut list = first.into_value();
+  list.extend(second.into_value());
+
+  ExistConcat {
+    size: seed1.new_named(list.len()),
+    list: seed2.new_named(list),
+    list_has_size: ListHasSize::new(),
+    is_sum: new_is_sum(),
+  }
+}
+
+/**
+ Maps each element of a list, preserving the size of the list.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::list::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+
+   let list = seed1.new_named(vec![1, 2, 3]);
+   let ExistSize {
+     size,
+     list_has_size,
+   } = len(seed2, &list);
+
+   let mapped = map(seed3, list, &list_has_size, |x| x * 10);
+
+   assert_eq!(mapped.list.value(), &vec![10, 20, 30]);
+   assert_eq!(mapped.list.value().len(), *size.value());
+ });
+ ```
+*/
+pub fn map<T, U, ListVal: HasType<Vec<T>>, SizeVal: HasType<usize>>(
+  seed: impl Seed,
+  list: Named<ListVal, Vec<T>>,
+  _list_has_size: &ListHasSize<T, SizeVal, ListVal>,
+  mapper: impl FnMut(T) -> U,
+) -> ExistMap<U, SizeVal, impl HasType<Vec<U>>>
+{
+  let list = list.into_value().into_iter().map(mapper).collect();
+
+  ExistMap {
+    list: seed.new_named(list),
+    list_has_size: ListHasSize::new(),
+  }
+}
+
+/**
+ A list can only have one size.
+*/
+pub fn size_unique<
+  T,
+  ListVal: HasType<Vec<T>>,
+  FirstSizeVal: HasType<usize>,
+  SecondSizeVal: HasType<usize>,
+>(
+  _first_has_size: &ListHasSize<T, FirstSizeVal, ListVal>,
+  _second_has_size: &ListHasSize<T, SecondSizeVal, ListVal>,
+) -> IsEqual<usize, FirstSizeVal, SecondSizeVal>
+{
+  new_is_equal()
+}
+
+pub fn permutation_preserve_size<
+  T,
+  NewListVal: HasType<Vec<T>>,
+  OldListVal: HasType<Vec<T>>,
+  SizeVal: HasType<usize>,
+>(
+  _list_has_size: &ListHasSize<T, SizeVal, OldListVal>,
+  _permutation_of: &PermutationOf<T, NewListVal, OldListVal>,
+) -> ListHasSize<T, SizeVal, NewListVal>
+{
+  ListHasSize::new()
+}
+
+pub fn positive_size_non_empty<
+  T,
+  ListVal: HasType<Vec<T>>,
+  SizeVal: HasType<usize>,
+>(
+  _list_has_size: &ListHasSize<T, SizeVal, ListVal>,
+  _size_positive: &Positive<usize, SizeVal>,
+) -> NonEmpty<Vec<T>, ListVal>
+{
+  new_non_empty()
+}
+
+/**
+ Any index that is less than the size of a list is in bounds
+ of the list.
+*/
+pub fn less_than_size_in_bounds<
+  T,
+  ListVal: HasType<Vec<T>>,
+  SizeVal: HasType<usize>,
+  IndexVal: HasType<usize>,
+>(
+  _list_has_size: &ListHasSize<T, SizeVal, ListVal>,
+  _index_less_than_size: &LessThan<usize, IndexVal, SizeVal>,
+) -> InBounds<Vec<T>, IndexVal, ListVal>
+{
+  new_in_bounds()
+}
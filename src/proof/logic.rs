@@ -0,0 +1,349 @@
+/*!
+ Generic combinators for building compound proofs out of any
+ proof types, such as the ones generated by [`proof!`](crate::proof!).
+*/
+
+use crate::{
+  macros::Sealed,
+  proof::Proof,
+};
+
+/**
+ A proof that both `P` and `Q` hold.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::{logic::*, num::*};
+ fn positive_and_non_zero<NumVal: HasType<u32>>(
+   num: &Named<NumVal, u32>,
+ ) -> Option<And<Positive<u32, NumVal>, NonZero<u32, NumVal>>>
+ {
+   let positive = check_positive(num)?;
+   let non_zero = positive_non_zero(&positive);
+   Some(And::join(positive, non_zero))
+ }
+ ```
+*/
+#[derive(Clone, Copy, Debug)]
+pub struct And<P, Q>(P, Q);
+
+/**
+ A proof that either `P` or `Q` holds. The two cases can be
+ analyzed by pattern matching on the enum, or with [`Or::elim`].
+*/
+#[derive(Clone, Copy, Debug)]
+pub enum Or<P, Q>
+{
+  Left(P),
+  Right(Q),
+}
+
+/**
+ A proof that `Q` holds whenever `P` holds, in the form of a function
+ that turns a proof of `P` into a proof of `Q`. Lemmas that take a
+ proof by reference, such as
+ [`positive_non_zero`](crate::proof::num::positive_non_zero), can be
+ turned directly into an `Implies`.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::{logic::*, num::*};
+ fn non_zero_if_positive<NumVal: HasType<u32>>(
+ ) -> Implies<Positive<u32, NumVal>, NonZero<u32, NumVal>>
+ {
+   Implies::new(positive_non_zero)
+ }
+ ```
+*/
+pub struct Implies<P, Q>(fn(&P) -> Q);
+
+impl<P, Q> And<P, Q>
+{
+  pub fn join(
+    first: P,
+    second: Q,
+  ) -> Self
+  {
+    And(first, second)
+  }
+
+  pub fn split(self) -> (P, Q)
+  {
+    (self.0, self.1)
+  }
+
+  pub fn first(&self) -> &P
+  {
+    &self.0
+  }
+
+  pub fn second(&self) -> &Q
+  {
+    &self.1
+  }
+
+  pub fn swap(self) -> And<Q, P>
+  {
+    And(self.1, self.0)
+  }
+}
+
+impl<P, Q> Or<P, Q>
+{
+  /**
+   Case analysis on the disjunction, where both cases must lead to
+   the same result.
+  */
+  pub fn elim<R>(
+    self,
+    on_left: impl FnOnce(P) -> R,
+    on_right: impl FnOnce(Q) -> R,
+  ) -> R
+  {
+    match self {
+      Or::Left(left) => on_left(left),
+      Or::Right(right) => on_right(right),
+    }
+  }
+
+  pub fn swap(self) -> Or<Q, P>
+  {
+    match self {
+      Or::Left(left) => Or::Right(left),
+      Or::Right(right) => Or::Left(right),
+    }
+  }
+}
+
+impl<P, Q> Implies<P, Q>
+{
+  pub fn new(implication: fn(&P) -> Q) -> Self
+  {
+    Implies(implication)
+  }
+
+  /**
+   Applies the implication to a proof of `P` to get a proof of `Q`.
+  */
+  pub fn apply(
+    &self,
+    proof: &P,
+  ) -> Q
+  {
+    (self.0)(proof)
+  }
+}
+
+impl<P, Q> Clone for Implies<P, Q>
+{
+  fn clone(&self) -> Self
+  {
+    *self
+  }
+}
+
+impl<P, Q> Copy for Implies<P, Q> {}
+
+/**
+ Implemented by proof types that have a corresponding negation proof
+ type, which is usually generated by [`proof!`](crate::proof!) with
+ the `else` clause:
+
+ ```rust
+ # use mononym::*;
+ proof! {
+   IsNatural(num: i64) else IsNegative;
+ }
+ ```
+
+ The constructor of the negation type is private to the module that
+ defines it, in the same way as the original proof type. So a
+ [`Not<P>`](Not) can only be constructed by the functions that can
+ refute `P`.
+*/
+pub trait Negatable
+{
+  type Negation;
+}
+
+/**
+ A proof that `P` does not hold, which is backed by a proof of the
+ negation of `P`.
+*/
+pub struct Not<P: Negatable>(P::Negation);
+
+/**
+ The result of deciding whether `P` holds, with the failure case
+ carrying a proof that `P` does not hold.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::logic::*;
+ mod natural {
+   use mononym::{*, proof::logic::*};
+
+   proof! {
+     IsNatural(num: i64) else IsNegative;
+   }
+
+   pub fn decide_natural<NumVal: HasType<i64>>(
+     num: &Named<NumVal, i64>,
+   ) -> Decision<IsNatural<NumVal>>
+   {
+     if *num.value() >= 0 {
+       Decision::Proved(IsNatural::new())
+     } else {
+       Decision::Refuted(IsNatural::refute())
+     }
+   }
+ }
+
+ with_seed(|life| {
+   let num = life.into_seed().new_named(-1);
+   match natural::decide_natural(&num) {
+     Decision::Proved(_) => panic!("-1 should not be natural"),
+     Decision::Refuted(_not_natural) => {}
+   }
+ });
+ ```
+*/
+pub enum Decision<P: Negatable>
+{
+  Proved(P),
+  Refuted(Not<P>),
+}
+
+/**
+ A conjunction does not hold if either of the proofs does not hold.
+*/
+impl<P: Negatable, Q: Negatable> Negatable for And<P, Q>
+{
+  type Negation = Or<Not<P>, Not<Q>>;
+}
+
+/**
+ A disjunction does not hold if both of the proofs do not hold.
+*/
+impl<P: Negatable, Q: Negatable> Negatable for Or<P, Q>
+{
+  type Negation = And<Not<P>, Not<Q>>;
+}
+
+impl<P: Negatable> Not<P>
+{
+  pub fn new(negation: P::Negation) -> Self
+  {
+    Not(negation)
+  }
+
+  pub fn negation(&self) -> &P::Negation
+  {
+    &self.0
+  }
+
+  pub fn into_negation(self) -> P::Negation
+  {
+    self.0
+  }
+
+  /**
+   Having both a proof of `P` and a proof of `Not<P>` is a
+   contradiction, from which anything follows. This can only
+   happen if the functions constructing the proofs are incorrect,
+   in which case the function panics.
+  */
+  pub fn contradict<R>(
+    &self,
+    _proof: &P,
+  ) -> R
+  {
+    unreachable!("a proof cannot be both proved and refuted")
+  }
+}
+
+impl<P: Negatable> Clone for Not<P>
+where
+  P::Negation: Clone,
+{
+  fn clone(&self) -> Self
+  {
+    Not(self.0.clone())
+  }
+}
+
+impl<P: Negatable> Copy for Not<P> where P::Negation: Copy {}
+
+impl<P: Negatable> core::fmt::Debug for Not<P>
+where
+  P::Negation: core::fmt::Debug,
+{
+  fn fmt(
+    &self,
+    f: &mut core::fmt::Formatter<'_>,
+  ) -> core::fmt::Result
+  {
+    f.debug_tuple("Not").field(&self.0).finish()
+  }
+}
+
+impl<P: Proof, Q: Proof> Sealed for And<P, Q> {}
+
+impl<P: Proof, Q: Proof> Proof for And<P, Q>
+{
+  type Subjects = (P::Subjects, Q::Subjects);
+
+  const NAME: &'static str = "And";
+
+  const DESCRIPTION: &'static str = "both of the two proofs hold";
+}
+
+impl<P: Proof, Q: Proof> Sealed for Or<P, Q> {}
+
+impl<P: Proof, Q: Proof> Proof for Or<P, Q>
+{
+  type Subjects = (P::Subjects, Q::Subjects);
+
+  const NAME: &'static str = "Or";
+
+  const DESCRIPTION: &'static str = "either of the two proofs holds";
+}
+
+impl<P: Proof + Negatable> Sealed for Not<P> where P::Negation: Proof {}
+
+impl<P: Proof + Negatable> Proof for Not<P>
+where
+  P::Negation: Proof,
+{
+  type Subjects = P::Subjects;
+
+  const NAME: &'static str = "Not";
+
+  const DESCRIPTION: &'static str = "the proof does not hold";
+}
+
+impl<P: Negatable> Decision<P>
+{
+  pub fn proved(self) -> Option<P>
+  {
+    match self {
+      Decision::Proved(proof) => Some(proof),
+      Decision::Refuted(_) => None,
+    }
+  }
+
+  pub fn refuted(self) -> Option<Not<P>>
+  {
+    match self {
+      Decision::Proved(_) => None,
+      Decision::Refuted(refutation) => Some(refutation),
+    }
+  }
+
+  pub fn into_result(self) -> Result<P, Not<P>>
+  {
+    match self {
+      Decision::Proved(proof) => Ok(proof),
+      Decision::Refuted(refutation) => Err(refutation),
+    }
+  }
+}
@@ -0,0 +1,142 @@
+/*!
+ Proofs that a key is present in a named [`BTreeMap`], so that
+ the entry can be looked up without handling the missing case.
+*/
+
+use alloc::collections::BTreeMap;
+
+use crate::named::*;
+
+crate::proof! {
+  HasKey<K, V>(map: BTreeMap<K, V>, key: K);
+  InsertedFrom<K, V>(
+    new_map: BTreeMap<K, V>,
+    old_map: BTreeMap<K, V>,
+    key: K,
+  );
+}
+
+pub struct ExistInsert<
+  K,
+  V,
+  NewMapVal: HasType<BTreeMap<K, V>>,
+  OldMapVal: HasType<BTreeMap<K, V>>,
+  KeyVal: HasType<K>,
+> {
+  pub new_map: Named<NewMapVal, BTreeMap<K, V>>,
+  pub has_key: HasKey<K, V, NewMapVal, KeyVal>,
+  pub inserted_from: InsertedFrom<K, V, NewMapVal, OldMapVal, KeyVal>,
+}
+
+pub fn check_has_key<
+  K: Ord,
+  V,
+  MapVal: HasType<BTreeMap<K, V>>,
+  KeyVal: HasType<K>,
+>(
+  map: impl AsNamedRef<MapVal, BTreeMap<K, V>>,
+  key: impl AsNamedRef<KeyVal, K>,
+) -> Option<HasKey<K, V, MapVal, KeyVal>>
+{
+  let map = map.as_named_ref();
+  let key = key.as_named_ref();
+  if map.value().contains_key(key.value()) {
+    Some(HasKey::new())
+  } else {
+    None
+  }
+}
+
+/**
+ Gets the value of a key that is known to be present in the map.
+ The map can be given as `&Named` or as a [`NamedRef`], with the
+ returned reference living as long as the borrowed map.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::map::*;
+ # use std::collections::BTreeMap;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+
+   let map = seed1.new_named(BTreeMap::from([(1, "one")]));
+   let key = seed2.new_named(2);
+
+   assert!(check_has_key(&map, &key).is_none());
+
+   let ExistInsert {
+     new_map, has_key, ..
+   } = insert(seed3, map, &key, "two");
+
+   assert_eq!(*get(&new_map, &key, &has_key), "two");
+
+   let map_ref = new_map.as_named_ref();
+   assert_eq!(*get(map_ref, &key, &has_key), "two");
+ });
+ ```
+*/
+pub fn get<
+  'a,
+  K: Ord + 'a,
+  V: 'a,
+  MapVal: HasType<BTreeMap<K, V>>,
+  KeyVal: HasType<K>,
+>(
+  map: impl Into<NamedRef<'a, MapVal, BTreeMap<K, V>>>,
+  key: impl AsNamedRef<KeyVal, K>,
+  _has_key: &HasKey<K, V, MapVal, KeyVal>,
+) -> &'a V
+{
+  let map = map.into();
+  let key = key.as_named_ref();
+  match map.value().get(key.value()) {
+    Some(value) => value,
+    None => unreachable!("HasKey proof was constructed for a missing key"),
+  }
+}
+
+/**
+ Inserts a key-value pair into the map, returning the new map
+ with a new name together with the proof that it has the key.
+ Keys that are present in the old map remain present in the new
+ map through [`has_key_preserved`].
+*/
+pub fn insert<
+  S: Seed,
+  K: Ord + Clone,
+  V,
+  MapVal: HasType<BTreeMap<K, V>>,
+  KeyVal: HasType<K>,
+>(
+  seed: S,
+  map: Named<MapVal, BTreeMap<K, V>>,
+  key: impl AsNamedRef<KeyVal, K>,
+  value: V,
+) -> ExistInsert<K, V, S::Name<BTreeMap<K, V>>, MapVal, KeyVal>
+{
+  let key = key.as_named_ref();
+  let mut map = map.into_value();
+  map.insert(key.value().clone(), value);
+
+  ExistInsert {
+    new_map: seed.new_named(map),
+    has_key: HasKey::new(),
+    inserted_from: InsertedFrom::new(),
+  }
+}
+
+pub fn has_key_preserved<
+  K: Ord,
+  V,
+  NewMapVal: HasType<BTreeMap<K, V>>,
+  OldMapVal: HasType<BTreeMap<K, V>>,
+  InsertedKeyVal: HasType<K>,
+  KeyVal: HasType<K>,
+>(
+  _has_key: &HasKey<K, V, OldMapVal, KeyVal>,
+  _inserted_from: &InsertedFrom<K, V, NewMapVal, OldMapVal, InsertedKeyVal>,
+) -> HasKey<K, V, NewMapVal, KeyVal>
+{
+  HasKey::new()
+}
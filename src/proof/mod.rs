@@ -1 +1,83 @@
+/*!
+ Proof types and functions provided by `mononym`, along with the
+ [`Proof`] trait implemented by all proof types.
+*/
+
+pub mod arith;
 pub mod equal;
+pub mod field;
+pub mod index;
+#[cfg(feature = "alloc")]
+pub mod list;
+pub mod logic;
+#[cfg(feature = "alloc")]
+pub mod map;
+pub mod non_empty;
+pub mod num;
+pub mod ord;
+pub mod pair;
+#[cfg(feature = "alloc")]
+pub mod sort;
+
+/**
+ Implemented by all proof types generated by [`proof!`](crate::proof!)
+ and [`exists!`](crate::exists), as well as the proof combinators in
+ [`logic`]. This allows generic code such as logging and containers
+ of proofs to be written once for all proof types.
+
+ The trait has a hidden supertrait that is implemented by the
+ macros. Since the macros expand in other crates, the supertrait
+ has to be public, so `Proof` is not strictly sealed. Implementing
+ it without the macros is not supported, and may break in future
+ versions.
+
+ ```rust
+ # use mononym::{*, proof::Proof};
+ proof! {
+   IsNatural(num: i64);
+ }
+
+ fn check_natural<NumVal: HasType<i64>>(
+   num: &Named<NumVal, i64>,
+ ) -> Option<IsNatural<NumVal>>
+ {
+   (*num.value() >= 0).then(IsNatural::new)
+ }
+
+ fn describe<P: Proof>(_proof: &P) -> (&'static str, &'static str)
+ {
+   (P::NAME, P::DESCRIPTION)
+ }
+
+ with_seed(|life| {
+   let num = life.into_seed().new_named(1);
+   let proof = check_natural(&num).expect("1 should be natural");
+   let copied = proof;
+
+   let (name, description) = describe(&proof);
+   assert_eq!(name, "IsNatural");
+   assert!(description.starts_with("IsNatural"));
+   assert_eq!(format!("{:?}", copied), "IsNatural");
+ });
+ ```
+*/
+pub trait Proof:
+  Clone + Copy + core::fmt::Debug + Send + Sync + crate::macros::Sealed
+{
+  /**
+   The tuple of names of the values that the proof is about, such as
+   `(FirstVal, SecondVal)` for `IsEqual<T, FirstVal, SecondVal>`.
+  */
+  type Subjects;
+
+  /**
+   The name of the proof type.
+  */
+  const NAME: &'static str;
+
+  /**
+   A description of the proof type, which is the proof definition
+   given to the macro by default.
+  */
+  const DESCRIPTION: &'static str;
+}
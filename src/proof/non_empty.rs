@@ -0,0 +1,352 @@
+/*!
+ Proofs that a named list is non-empty, so that operations such as
+ [`first`] and [`min`] can return the element without an [`Option`].
+*/
+
+#[cfg(feature = "alloc")]
+use crate::proof::sort::PermutationOf;
+use crate::{
+  internal::Sealed,
+  named::*,
+};
+
+crate::proof! {
+  NonEmpty<C>(list: C) where C: ?Sized;
+
+  /**
+   A proof that the named element `ElemVal` is an element of the
+   named list `ListVal`.
+  */
+  ElemOf(elem, list);
+
+  /**
+   A proof that the named element `ElemVal` is the smallest element of
+   the named list `ListVal`.
+  */
+  IsMin(elem, list);
+
+  /**
+   A proof that the named element `ElemVal` is the largest element of
+   the named list `ListVal`.
+  */
+  IsMax(elem, list);
+}
+
+/**
+ Implemented by the list types that can have a [`NonEmpty`] proof.
+ The items are references to the elements for lists such as
+ `Vec<T>`, and `char` for strings.
+
+ This trait is sealed, as the functions in this module rely on
+ `items` always returning the same non-empty iterator for a
+ list value that was checked to be non-empty.
+*/
+pub trait Sequence: Sealed
+{
+  type Item<'a>
+  where
+    Self: 'a;
+
+  fn items(&self) -> impl DoubleEndedIterator<Item = Self::Item<'_>>;
+}
+
+impl<T, const N: usize> Sequence for [T; N]
+{
+  type Item<'a>
+    = &'a T
+  where
+    T: 'a;
+
+  fn items(&self) -> impl DoubleEndedIterator<Item = &T>
+  {
+    self.iter()
+  }
+}
+
+impl<T> Sequence for [T]
+{
+  type Item<'a>
+    = &'a T
+  where
+    T: 'a;
+
+  fn items(&self) -> impl DoubleEndedIterator<Item = &T>
+  {
+    self.iter()
+  }
+}
+
+impl<T> Sequence for &[T]
+{
+  type Item<'a>
+    = &'a T
+  where
+    Self: 'a;
+
+  fn items(&self) -> impl DoubleEndedIterator<Item = &T>
+  {
+    self.iter()
+  }
+}
+
+impl Sequence for str
+{
+  type Item<'a> = char;
+
+  fn items(&self) -> impl DoubleEndedIterator<Item = char>
+  {
+    self.chars()
+  }
+}
+
+impl Sequence for &str
+{
+  type Item<'a>
+    = char
+  where
+    Self: 'a;
+
+  fn items(&self) -> impl DoubleEndedIterator<Item = char>
+  {
+    self.chars()
+  }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_impls
+{
+  use alloc::{
+    boxed::Box,
+    collections::VecDeque,
+    string::String,
+    vec::Vec,
+  };
+
+  use super::Sequence;
+
+  impl<T> Sequence for Vec<T>
+  {
+    type Item<'a>
+      = &'a T
+    where
+      T: 'a;
+
+    fn items(&self) -> impl DoubleEndedIterator<Item = &T>
+    {
+      self.iter()
+    }
+  }
+
+  impl<T> Sequence for Box<[T]>
+  {
+    type Item<'a>
+      = &'a T
+    where
+      T: 'a;
+
+    fn items(&self) -> impl DoubleEndedIterator<Item = &T>
+    {
+      self.iter()
+    }
+  }
+
+  impl<T> Sequence for VecDeque<T>
+  {
+    type Item<'a>
+      = &'a T
+    where
+      T: 'a;
+
+    fn items(&self) -> impl DoubleEndedIterator<Item = &T>
+    {
+      self.iter()
+    }
+  }
+
+  impl Sequence for String
+  {
+    type Item<'a> = char;
+
+    fn items(&self) -> impl DoubleEndedIterator<Item = char>
+    {
+      self.chars()
+    }
+  }
+}
+
+pub struct ExistMin<'a, C: Sequence + ?Sized + 'a, ElemVal, ListVal>
+where
+  ElemVal: HasType<C::Item<'a>>,
+{
+  pub elem: Named<ElemVal, C::Item<'a>>,
+  pub elem_of: ElemOf<ElemVal, ListVal>,
+  pub is_min: IsMin<ElemVal, ListVal>,
+}
+
+pub struct ExistMax<'a, C: Sequence + ?Sized + 'a, ElemVal, ListVal>
+where
+  ElemVal: HasType<C::Item<'a>>,
+{
+  pub elem: Named<ElemVal, C::Item<'a>>,
+  pub elem_of: ElemOf<ElemVal, ListVal>,
+  pub is_max: IsMax<ElemVal, ListVal>,
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn new_non_empty<C: Sequence + ?Sized, ListVal: HasType<C>>(
+) -> NonEmpty<C, ListVal>
+{
+  NonEmpty::new()
+}
+
+pub fn check_non_empty<C: Sequence + ?Sized, ListVal: HasType<C>>(
+  list: impl AsNamedRef<ListVal, C>
+) -> Option<NonEmpty<C, ListVal>>
+{
+  let list = list.as_named_ref();
+  if list.value().items().next().is_some() {
+    Some(NonEmpty::new())
+  } else {
+    None
+  }
+}
+
+/**
+ Gets the first element of a non-empty list.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::non_empty::*;
+ with_seed(|life| {
+   let list = life.into_seed().new_named([2, 3, 1]);
+   let non_empty = check_non_empty(&list).expect("list should be non-empty");
+
+   assert_eq!(*first(&list, &non_empty), 2);
+   assert_eq!(*last(&list, &non_empty), 1);
+   assert_eq!(*min(&list, &non_empty), 1);
+   assert_eq!(*max(&list, &non_empty), 3);
+   assert_eq!(reduce(&list, &non_empty, |x, y| if x > y { y } else { x }), &1);
+ });
+ ```
+*/
+pub fn first<'a, C: Sequence + ?Sized + 'a, ListVal: HasType<C>>(
+  list: impl Into<NamedRef<'a, ListVal, C>>,
+  _non_empty: &NonEmpty<C, ListVal>,
+) -> C::Item<'a>
+{
+  match list.into().value().items().next() {
+    Some(elem) => elem,
+    None => unreachable!("NonEmpty proof was constructed for an empty list"),
+  }
+}
+
+pub fn last<'a, C: Sequence + ?Sized + 'a, ListVal: HasType<C>>(
+  list: impl Into<NamedRef<'a, ListVal, C>>,
+  _non_empty: &NonEmpty<C, ListVal>,
+) -> C::Item<'a>
+{
+  match list.into().value().items().next_back() {
+    Some(elem) => elem,
+    None => unreachable!("NonEmpty proof was constructed for an empty list"),
+  }
+}
+
+pub fn min<'a, C: Sequence + ?Sized + 'a, ListVal: HasType<C>>(
+  list: impl Into<NamedRef<'a, ListVal, C>>,
+  non_empty: &NonEmpty<C, ListVal>,
+) -> C::Item<'a>
+where
+  C::Item<'a>: Ord,
+{
+  reduce(list, non_empty, core::cmp::min)
+}
+
+pub fn max<'a, C: Sequence + ?Sized + 'a, ListVal: HasType<C>>(
+  list: impl Into<NamedRef<'a, ListVal, C>>,
+  non_empty: &NonEmpty<C, ListVal>,
+) -> C::Item<'a>
+where
+  C::Item<'a>: Ord,
+{
+  reduce(list, non_empty, core::cmp::max)
+}
+
+/**
+ Reduces the elements of a non-empty list into a single value
+ by repeatedly applying the reducer function, the same as
+ [`Iterator::reduce`] but without returning an `Option`.
+*/
+pub fn reduce<'a, C: Sequence + ?Sized + 'a, ListVal: HasType<C>>(
+  list: impl Into<NamedRef<'a, ListVal, C>>,
+  _non_empty: &NonEmpty<C, ListVal>,
+  reducer: impl FnMut(C::Item<'a>, C::Item<'a>) -> C::Item<'a>,
+) -> C::Item<'a>
+{
+  match list.into().value().items().reduce(reducer) {
+    Some(elem) => elem,
+    None => unreachable!("NonEmpty proof was constructed for an empty list"),
+  }
+}
+
+/**
+ Gets the smallest element of a non-empty list as a named value,
+ together with the proofs that it is the smallest element of
+ the list.
+*/
+pub fn min_elem<'a, C: Sequence + ?Sized + 'a, ListVal: HasType<C>>(
+  seed: impl Seed,
+  list: impl Into<NamedRef<'a, ListVal, C>>,
+  non_empty: &NonEmpty<C, ListVal>,
+) -> ExistMin<'a, C, impl HasType<C::Item<'a>>, ListVal>
+where
+  C::Item<'a>: Ord,
+{
+  ExistMin {
+    elem: seed.new_named(min(list, non_empty)),
+    elem_of: ElemOf::new(),
+    is_min: IsMin::new(),
+  }
+}
+
+/**
+ Gets the largest element of a non-empty list as a named value,
+ together with the proofs that it is the largest element of
+ the list.
+*/
+pub fn max_elem<'a, C: Sequence + ?Sized + 'a, ListVal: HasType<C>>(
+  seed: impl Seed,
+  list: impl Into<NamedRef<'a, ListVal, C>>,
+  non_empty: &NonEmpty<C, ListVal>,
+) -> ExistMax<'a, C, impl HasType<C::Item<'a>>, ListVal>
+where
+  C::Item<'a>: Ord,
+{
+  ExistMax {
+    elem: seed.new_named(max(list, non_empty)),
+    elem_of: ElemOf::new(),
+    is_max: IsMax::new(),
+  }
+}
+
+/**
+ A list that contains an element is non-empty.
+*/
+pub fn elem_of_non_empty<C: Sequence + ?Sized, ElemVal, ListVal: HasType<C>>(
+  _elem_of: &ElemOf<ElemVal, ListVal>
+) -> NonEmpty<C, ListVal>
+{
+  NonEmpty::new()
+}
+
+#[cfg(feature = "alloc")]
+pub fn permutation_preserve_non_empty<
+  T,
+  NewListVal: HasType<alloc::vec::Vec<T>>,
+  OldListVal: HasType<alloc::vec::Vec<T>>,
+>(
+  _non_empty: &NonEmpty<alloc::vec::Vec<T>, OldListVal>,
+  _permutation_of: &PermutationOf<T, NewListVal, OldListVal>,
+) -> NonEmpty<alloc::vec::Vec<T>, NewListVal>
+{
+  NonEmpty::new()
+}
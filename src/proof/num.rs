@@ -0,0 +1,337 @@
+/*!
+ Refinement proofs on the primitive integer types, such as proofs
+ that a named integer is non-zero, positive, or within a range.
+*/
+
+use crate::{
+  internal::Sealed,
+  named::*,
+  proof::ord::{
+    new_less_than_eq,
+    LessThanEq,
+  },
+};
+
+crate::proof! {
+  NonZero<T>(num: T);
+  Positive<T>(num: T);
+  NonNegative<T>(num: T);
+  InRange<T>(num: T, low: T, high: T);
+}
+
+/**
+ Implemented by all primitive integer types. This trait is sealed
+ and cannot be implemented outside of `mononym`.
+*/
+pub trait Integer:
+  Copy
+  + Ord
+  + core::ops::Div<Output = Self>
+  + core::ops::Rem<Output = Self>
+  + Sealed
+{
+  /**
+   The [`core::num`] non-zero counterpart of the integer type,
+   e.g. [`NonZeroU32`](core::num::NonZeroU32) for `u32`.
+  */
+  type NonZero;
+
+  const ZERO: Self;
+
+  #[doc(hidden)]
+  fn into_non_zero(self) -> Option<Self::NonZero>;
+
+  #[doc(hidden)]
+  fn checked_add(
+    self,
+    other: Self,
+  ) -> Option<Self>;
+
+  #[doc(hidden)]
+  fn checked_sub(
+    self,
+    other: Self,
+  ) -> Option<Self>;
+
+  #[doc(hidden)]
+  fn checked_mul(
+    self,
+    other: Self,
+  ) -> Option<Self>;
+}
+
+/**
+ Implemented by the unsigned primitive integer types, for which
+ a non-zero integer is also always positive.
+*/
+pub trait Unsigned: Integer {}
+
+macro_rules! impl_integer {
+  ( $( $type:ty ),* ) => {
+    $(
+      impl Integer for $type
+      {
+        type NonZero = core::num::NonZero<$type>;
+
+        const ZERO: Self = 0;
+
+        fn into_non_zero(self) -> Option<Self::NonZero>
+        {
+          core::num::NonZero::new(self)
+        }
+
+        fn checked_add(
+          self,
+          other: Self,
+        ) -> Option<Self>
+        {
+          <$type>::checked_add(self, other)
+        }
+
+        fn checked_sub(
+          self,
+          other: Self,
+        ) -> Option<Self>
+        {
+          <$type>::checked_sub(self, other)
+        }
+
+        fn checked_mul(
+          self,
+          other: Self,
+        ) -> Option<Self>
+        {
+          <$type>::checked_mul(self, other)
+        }
+      }
+    )*
+  };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Unsigned for u8 {}
+impl Unsigned for u16 {}
+impl Unsigned for u32 {}
+impl Unsigned for u64 {}
+impl Unsigned for u128 {}
+impl Unsigned for usize {}
+
+pub fn check_non_zero<T: Integer, NumVal: HasType<T>>(
+  num: impl AsNamedRef<NumVal, T>
+) -> Option<NonZero<T, NumVal>>
+{
+  let num = num.as_named_ref();
+  if *num.value() != T::ZERO {
+    Some(NonZero::new())
+  } else {
+    None
+  }
+}
+
+pub fn check_positive<T: Integer, NumVal: HasType<T>>(
+  num: impl AsNamedRef<NumVal, T>
+) -> Option<Positive<T, NumVal>>
+{
+  let num = num.as_named_ref();
+  if *num.value() > T::ZERO {
+    Some(Positive::new())
+  } else {
+    None
+  }
+}
+
+pub fn check_non_negative<T: Integer, NumVal: HasType<T>>(
+  num: impl AsNamedRef<NumVal, T>
+) -> Option<NonNegative<T, NumVal>>
+{
+  let num = num.as_named_ref();
+  if *num.value() >= T::ZERO {
+    Some(NonNegative::new())
+  } else {
+    None
+  }
+}
+
+/**
+ Checks that `low <= num <= high`, with both bounds inclusive.
+*/
+pub fn check_in_range<
+  T: Integer,
+  NumVal: HasType<T>,
+  LowVal: HasType<T>,
+  HighVal: HasType<T>,
+>(
+  num: impl AsNamedRef<NumVal, T>,
+  low: impl AsNamedRef<LowVal, T>,
+  high: impl AsNamedRef<HighVal, T>,
+) -> Option<InRange<T, NumVal, LowVal, HighVal>>
+{
+  let num = num.as_named_ref();
+  let low = low.as_named_ref();
+  let high = high.as_named_ref();
+  if low.value() <= num.value() && num.value() <= high.value() {
+    Some(InRange::new())
+  } else {
+    None
+  }
+}
+
+/**
+ Unsigned integers are always non-negative.
+*/
+pub fn unsigned_non_negative<T: Unsigned, NumVal: HasType<T>>(
+  _num: impl AsNamedRef<NumVal, T>
+) -> NonNegative<T, NumVal>
+{
+  NonNegative::new()
+}
+
+pub fn positive_non_zero<T: Integer, NumVal: HasType<T>>(
+  _positive: &Positive<T, NumVal>
+) -> NonZero<T, NumVal>
+{
+  NonZero::new()
+}
+
+pub fn positive_non_negative<T: Integer, NumVal: HasType<T>>(
+  _positive: &Positive<T, NumVal>
+) -> NonNegative<T, NumVal>
+{
+  NonNegative::new()
+}
+
+pub fn non_zero_non_negative_positive<T: Integer, NumVal: HasType<T>>(
+  _non_zero: &NonZero<T, NumVal>,
+  _non_negative: &NonNegative<T, NumVal>,
+) -> Positive<T, NumVal>
+{
+  Positive::new()
+}
+
+pub fn non_zero_unsigned_positive<T: Unsigned, NumVal: HasType<T>>(
+  _non_zero: &NonZero<T, NumVal>
+) -> Positive<T, NumVal>
+{
+  Positive::new()
+}
+
+pub fn in_range_from_bounds<
+  T: Integer,
+  NumVal: HasType<T>,
+  LowVal: HasType<T>,
+  HighVal: HasType<T>,
+>(
+  _low_lte_num: &LessThanEq<T, LowVal, NumVal>,
+  _num_lte_high: &LessThanEq<T, NumVal, HighVal>,
+) -> InRange<T, NumVal, LowVal, HighVal>
+{
+  InRange::new()
+}
+
+pub fn in_range_into_bounds<
+  T: Integer,
+  NumVal: HasType<T>,
+  LowVal: HasType<T>,
+  HighVal: HasType<T>,
+>(
+  _in_range: &InRange<T, NumVal, LowVal, HighVal>
+) -> (
+  LessThanEq<T, LowVal, NumVal>,
+  LessThanEq<T, NumVal, HighVal>,
+)
+{
+  (new_less_than_eq(), new_less_than_eq())
+}
+
+/**
+ Converts a named integer into its [`core::num`] non-zero counterpart,
+ such as [`NonZeroU32`](core::num::NonZeroU32), using the proof that
+ it is non-zero.
+*/
+pub fn into_non_zero<T: Integer, NumVal: HasType<T>>(
+  num: impl AsNamedRef<NumVal, T>,
+  _non_zero: &NonZero<T, NumVal>,
+) -> T::NonZero
+{
+  let num = num.as_named_ref();
+  match num.value().into_non_zero() {
+    Some(num) => num,
+    None => unreachable!("NonZero proof was constructed for a zero value"),
+  }
+}
+
+/**
+ Divides `x` by `y`, with the proof that `y` is non-zero ensuring that
+ the division never panics. This is only provided for unsigned
+ integers, as signed division of `MIN` by `-1` overflows. Use
+ [`div_positive`] for signed integers.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::num::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let x = seed1.new_named(7_u32);
+   let y = seed2.new_named(2_u32);
+
+   let y_non_zero = check_non_zero(&y).expect("y should be non-zero");
+
+   assert_eq!(div(&x, &y, &y_non_zero), 3);
+   assert_eq!(rem(&x, &y, &y_non_zero), 1);
+   assert_eq!(into_non_zero(&y, &y_non_zero).get(), 2);
+ });
+ ```
+*/
+pub fn div<T: Unsigned, XVal: HasType<T>, YVal: HasType<T>>(
+  x: impl AsNamedRef<XVal, T>,
+  y: impl AsNamedRef<YVal, T>,
+  _y_non_zero: &NonZero<T, YVal>,
+) -> T
+{
+  let x = x.as_named_ref();
+  let y = y.as_named_ref();
+  *x.value() / *y.value()
+}
+
+/**
+ Computes the remainder of `x` divided by `y`, with the proof
+ that `y` is non-zero ensuring that it never panics.
+*/
+pub fn rem<T: Unsigned, XVal: HasType<T>, YVal: HasType<T>>(
+  x: impl AsNamedRef<XVal, T>,
+  y: impl AsNamedRef<YVal, T>,
+  _y_non_zero: &NonZero<T, YVal>,
+) -> T
+{
+  let x = x.as_named_ref();
+  let y = y.as_named_ref();
+  *x.value() % *y.value()
+}
+
+/**
+ Divides `x` by `y` for any integer type. Requiring `y` to be
+ positive rules out both division by zero and the overflowing
+ division of `MIN` by `-1`.
+*/
+pub fn div_positive<T: Integer, XVal: HasType<T>, YVal: HasType<T>>(
+  x: impl AsNamedRef<XVal, T>,
+  y: impl AsNamedRef<YVal, T>,
+  _y_positive: &Positive<T, YVal>,
+) -> T
+{
+  let x = x.as_named_ref();
+  let y = y.as_named_ref();
+  *x.value() / *y.value()
+}
+
+pub fn rem_positive<T: Integer, XVal: HasType<T>, YVal: HasType<T>>(
+  x: impl AsNamedRef<XVal, T>,
+  y: impl AsNamedRef<YVal, T>,
+  _y_positive: &Positive<T, YVal>,
+) -> T
+{
+  let x = x.as_named_ref();
+  let y = y.as_named_ref();
+  *x.value() % *y.value()
+}
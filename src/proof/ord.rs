@@ -0,0 +1,317 @@
+use crate::{
+  named::*,
+  proof::{
+    equal::IsEqual,
+    logic::{
+      Decision,
+      Negatable,
+      Not,
+    },
+  },
+};
+
+crate::proof! {
+  LessThan<T>(first: T, second: T);
+  LessThanEq<T>(first: T, second: T);
+  GreaterThan<T>(first: T, second: T);
+
+  /**
+   A proof that `first` and `second` are equivalent according to
+   [`Ord`], i.e. `first.cmp(second)` is `Ordering::Equal`. Since `Ord`
+   does not have to agree with [`Eq`], this is weaker than
+   [`IsEqual`], and cannot be used to transport proofs.
+  */
+  OrdEquivalent<T>(first: T, second: T);
+}
+
+/**
+ `first < second` does not hold if and only if `second <= first`.
+*/
+impl<T, FirstVal: HasType<T>, SecondVal: HasType<T>> Negatable
+  for LessThan<T, FirstVal, SecondVal>
+{
+  type Negation = LessThanEq<T, SecondVal, FirstVal>;
+}
+
+/**
+ `first <= second` does not hold if and only if `second < first`.
+*/
+impl<T, FirstVal: HasType<T>, SecondVal: HasType<T>> Negatable
+  for LessThanEq<T, FirstVal, SecondVal>
+{
+  type Negation = LessThan<T, SecondVal, FirstVal>;
+}
+
+/**
+ `first > second` does not hold if and only if `first <= second`.
+*/
+impl<T, FirstVal: HasType<T>, SecondVal: HasType<T>> Negatable
+  for GreaterThan<T, FirstVal, SecondVal>
+{
+  type Negation = LessThanEq<T, FirstVal, SecondVal>;
+}
+
+/**
+ The proof-carrying counterpart of [`Ordering`](core::cmp::Ordering),
+ returned by [`compare`]. Each variant carries the proof of how the
+ first named value relates to the second named value.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::ord::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let x = seed1.new_named(2_u32);
+   let y = seed2.new_named(4_u32);
+
+   match compare(&x, &y) {
+     Comparison::Less(x_lt_y) => {
+       let _x_lte_y: LessThanEq<u32, _, _> = less_than_weaken(x_lt_y);
+     }
+     _ => panic!("expect x < y"),
+   }
+ });
+ ```
+*/
+pub enum Comparison<T, FirstVal: HasType<T>, SecondVal: HasType<T>>
+{
+  Less(LessThan<T, FirstVal, SecondVal>),
+  Equal(OrdEquivalent<T, FirstVal, SecondVal>),
+  Greater(GreaterThan<T, FirstVal, SecondVal>),
+}
+
+pub(crate) fn new_less_than<
+  T: Ord,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>() -> LessThan<T, FirstVal, SecondVal>
+{
+  LessThan::new()
+}
+
+pub(crate) fn new_less_than_eq<
+  T: Ord,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>() -> LessThanEq<T, FirstVal, SecondVal>
+{
+  LessThanEq::new()
+}
+
+pub fn compare<T: Ord, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Comparison<T, FirstVal, SecondVal>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  match first.value().cmp(second.value()) {
+    core::cmp::Ordering::Less => Comparison::Less(LessThan::new()),
+    core::cmp::Ordering::Equal => Comparison::Equal(OrdEquivalent::new()),
+    core::cmp::Ordering::Greater => Comparison::Greater(GreaterThan::new()),
+  }
+}
+
+pub fn check_less_than<T: Ord, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Option<LessThan<T, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  if first.value() < second.value() {
+    Some(LessThan::new())
+  } else {
+    None
+  }
+}
+
+pub fn check_less_than_eq<T: Ord, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Option<LessThanEq<T, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  if first.value() <= second.value() {
+    Some(LessThanEq::new())
+  } else {
+    None
+  }
+}
+
+pub fn check_greater_than<T: Ord, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Option<GreaterThan<T, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  if first.value() > second.value() {
+    Some(GreaterThan::new())
+  } else {
+    None
+  }
+}
+
+pub fn decide_less_than<T: Ord, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Decision<LessThan<T, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  if first.value() < second.value() {
+    Decision::Proved(LessThan::new())
+  } else {
+    Decision::Refuted(Not::new(LessThanEq::new()))
+  }
+}
+
+pub fn decide_less_than_eq<
+  T: Ord,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Decision<LessThanEq<T, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  if first.value() <= second.value() {
+    Decision::Proved(LessThanEq::new())
+  } else {
+    Decision::Refuted(Not::new(LessThan::new()))
+  }
+}
+
+pub fn decide_greater_than<
+  T: Ord,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  first: impl AsNamedRef<FirstVal, T>,
+  second: impl AsNamedRef<SecondVal, T>,
+) -> Decision<GreaterThan<T, FirstVal, SecondVal>>
+{
+  let first = first.as_named_ref();
+  let second = second.as_named_ref();
+  if first.value() > second.value() {
+    Decision::Proved(GreaterThan::new())
+  } else {
+    Decision::Refuted(Not::new(LessThanEq::new()))
+  }
+}
+
+pub fn less_than_transitive<
+  T: Ord,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+  ThirdVal: HasType<T>,
+>(
+  _first_second: LessThan<T, FirstVal, SecondVal>,
+  _second_third: LessThan<T, SecondVal, ThirdVal>,
+) -> LessThan<T, FirstVal, ThirdVal>
+{
+  LessThan::new()
+}
+
+pub fn less_than_eq_transitive<
+  T: Ord,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+  ThirdVal: HasType<T>,
+>(
+  _first_second: LessThanEq<T, FirstVal, SecondVal>,
+  _second_third: LessThanEq<T, SecondVal, ThirdVal>,
+) -> LessThanEq<T, FirstVal, ThirdVal>
+{
+  LessThanEq::new()
+}
+
+pub fn greater_than_transitive<
+  T: Ord,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+  ThirdVal: HasType<T>,
+>(
+  _first_second: GreaterThan<T, FirstVal, SecondVal>,
+  _second_third: GreaterThan<T, SecondVal, ThirdVal>,
+) -> GreaterThan<T, FirstVal, ThirdVal>
+{
+  GreaterThan::new()
+}
+
+/**
+ If `first <= second` and `second <= first`, then `first` and
+ `second` are equivalent according to [`Ord`]. This does not give an
+ [`IsEqual`], as the `Ord` implementation of `T` is not required to
+ agree with its `Eq` implementation.
+*/
+pub fn less_than_eq_antisymmetric<
+  T: Ord,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _first_second: LessThanEq<T, FirstVal, SecondVal>,
+  _second_first: LessThanEq<T, SecondVal, FirstVal>,
+) -> OrdEquivalent<T, FirstVal, SecondVal>
+{
+  OrdEquivalent::new()
+}
+
+pub fn less_than_weaken<T: Ord, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  _less_than: LessThan<T, FirstVal, SecondVal>
+) -> LessThanEq<T, FirstVal, SecondVal>
+{
+  LessThanEq::new()
+}
+
+pub fn equal_weaken<T: Ord, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  _is_equal: IsEqual<T, FirstVal, SecondVal>
+) -> LessThanEq<T, FirstVal, SecondVal>
+{
+  LessThanEq::new()
+}
+
+pub fn equivalent_weaken<T: Ord, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  _equivalent: OrdEquivalent<T, FirstVal, SecondVal>
+) -> LessThanEq<T, FirstVal, SecondVal>
+{
+  LessThanEq::new()
+}
+
+/**
+ Equivalence according to [`Ord`] is symmetric.
+*/
+pub fn equivalent_symmetric<
+  T: Ord,
+  FirstVal: HasType<T>,
+  SecondVal: HasType<T>,
+>(
+  _equivalent: OrdEquivalent<T, FirstVal, SecondVal>
+) -> OrdEquivalent<T, SecondVal, FirstVal>
+{
+  OrdEquivalent::new()
+}
+
+/**
+ `first > second` is the same as `second < first`.
+*/
+pub fn greater_than_flip<T: Ord, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  _greater_than: GreaterThan<T, FirstVal, SecondVal>
+) -> LessThan<T, SecondVal, FirstVal>
+{
+  LessThan::new()
+}
+
+/**
+ `first < second` is the same as `second > first`.
+*/
+pub fn less_than_flip<T: Ord, FirstVal: HasType<T>, SecondVal: HasType<T>>(
+  _less_than: LessThan<T, FirstVal, SecondVal>
+) -> GreaterThan<T, SecondVal, FirstVal>
+{
+  GreaterThan::new()
+}
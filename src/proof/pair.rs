@@ -0,0 +1,170 @@
+/*!
+ Splitting named pairs into named components, and joining named
+ values into named pairs, together with proofs of how the components
+ relate to the pair. The proofs do not mention the component types,
+ so the same proofs are used for the owned and the borrowed variants.
+*/
+
+use crate::{
+  named::*,
+  proof::equal::{
+    new_is_equal,
+    IsEqual,
+  },
+};
+
+crate::proof! {
+  FstOf(fst, pair);
+  SndOf(snd, pair);
+  PairOf(pair, fst, snd);
+}
+
+pub struct ExistSplit<A, B, FstVal: HasType<A>, SndVal: HasType<B>, PairVal>
+{
+  pub fst: Named<FstVal, A>,
+  pub snd: Named<SndVal, B>,
+  pub fst_of: FstOf<FstVal, PairVal>,
+  pub snd_of: SndOf<SndVal, PairVal>,
+}
+
+pub struct ExistPair<T, PairVal: HasType<T>, FstVal, SndVal>
+{
+  pub pair: Named<PairVal, T>,
+  pub pair_of: PairOf<PairVal, FstVal, SndVal>,
+}
+
+/**
+ Splits a named pair into its two components, each with a fresh name.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::pair::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let (seed3, seed4) = seed3.replicate();
+
+   let fst = seed1.new_named(1);
+   let snd = seed2.new_named("one");
+
+   let ExistPair { pair, pair_of } = pair(seed3, fst, snd);
+   assert_eq!(pair.value(), &(1, "one"));
+
+   let ExistSplit { fst, snd, fst_of, .. } = split(seed4, pair);
+   assert_eq!(*fst.value(), 1);
+   assert_eq!(*snd.value(), "one");
+
+   let _fst_of = pair_fst_of(&pair_of);
+ });
+ ```
+*/
+pub fn split<A, B, PairVal: HasType<(A, B)>>(
+  seed: impl Seed,
+  pair: Named<PairVal, (A, B)>,
+) -> ExistSplit<A, B, impl HasType<A>, impl HasType<B>, PairVal>
+{
+  let (seed1, seed2) = seed.replicate();
+  let (fst, snd) = pair.into_value();
+
+  ExistSplit {
+    fst: seed1.new_named(fst),
+    snd: seed2.new_named(snd),
+    fst_of: FstOf::new(),
+    snd_of: SndOf::new(),
+  }
+}
+
+/**
+ Borrows the two components of a named pair, giving each reference a
+ fresh name.
+*/
+pub fn split_ref<'a, A: 'a, B: 'a, PairVal: HasType<(A, B)>>(
+  seed: impl Seed,
+  pair: impl Into<NamedRef<'a, PairVal, (A, B)>>,
+) -> ExistSplit<&'a A, &'a B, impl HasType<&'a A>, impl HasType<&'a B>, PairVal>
+{
+  let (seed1, seed2) = seed.replicate();
+  let (fst, snd) = pair.into().value();
+
+  ExistSplit {
+    fst: seed1.new_named(fst),
+    snd: seed2.new_named(snd),
+    fst_of: FstOf::new(),
+    snd_of: SndOf::new(),
+  }
+}
+
+/**
+ Joins two named values into a named pair with a fresh name.
+*/
+pub fn pair<A, B, FstVal: HasType<A>, SndVal: HasType<B>>(
+  seed: impl Seed,
+  fst: Named<FstVal, A>,
+  snd: Named<SndVal, B>,
+) -> ExistPair<(A, B), impl HasType<(A, B)>, FstVal, SndVal>
+{
+  ExistPair {
+    pair: seed.new_named((fst.into_value(), snd.into_value())),
+    pair_of: PairOf::new(),
+  }
+}
+
+/**
+ Joins references to two named values into a named pair of
+ references with a fresh name.
+*/
+pub fn pair_ref<'a, A: 'a, B: 'a, FstVal: HasType<A>, SndVal: HasType<B>>(
+  seed: impl Seed,
+  fst: impl Into<NamedRef<'a, FstVal, A>>,
+  snd: impl Into<NamedRef<'a, SndVal, B>>,
+) -> ExistPair<(&'a A, &'a B), impl HasType<(&'a A, &'a B)>, FstVal, SndVal>
+{
+  ExistPair {
+    pair: seed.new_named((fst.into().value(), snd.into().value())),
+    pair_of: PairOf::new(),
+  }
+}
+
+pub fn pair_fst_of<PairVal, FstVal, SndVal>(
+  _pair_of: &PairOf<PairVal, FstVal, SndVal>
+) -> FstOf<FstVal, PairVal>
+{
+  FstOf::new()
+}
+
+pub fn pair_snd_of<PairVal, FstVal, SndVal>(
+  _pair_of: &PairOf<PairVal, FstVal, SndVal>
+) -> SndOf<SndVal, PairVal>
+{
+  SndOf::new()
+}
+
+/**
+ The first component of a named pair is unique, so a component
+ obtained with [`split`] is equal to the one given to [`pair`].
+*/
+pub fn fst_of_unique<
+  A: Eq,
+  FirstVal: HasType<A>,
+  SecondVal: HasType<A>,
+  PairVal,
+>(
+  _first: &FstOf<FirstVal, PairVal>,
+  _second: &FstOf<SecondVal, PairVal>,
+) -> IsEqual<A, FirstVal, SecondVal>
+{
+  new_is_equal()
+}
+
+pub fn snd_of_unique<
+  B: Eq,
+  FirstVal: HasType<B>,
+  SecondVal: HasType<B>,
+  PairVal,
+>(
+  _first: &SndOf<FirstVal, PairVal>,
+  _second: &SndOf<SecondVal, PairVal>,
+) -> IsEqual<B, FirstVal, SecondVal>
+{
+  new_is_equal()
+}
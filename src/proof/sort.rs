@@ -0,0 +1,237 @@
+/*!
+ Proofs that a named list is sorted, together with operations
+ such as binary search that are only correct on sorted lists.
+*/
+
+use alloc::vec::Vec;
+
+use crate::{
+  named::*,
+  proof::index::{
+    new_in_bounds,
+    InBounds,
+  },
+};
+
+crate::proof! {
+  Sorted<T>(list: Vec<T>);
+  Distinct<T>(list: Vec<T>);
+  PermutationOf<T>(new_list: Vec<T>, old_list: Vec<T>);
+}
+
+pub struct ExistSorted<
+  T,
+  NewListVal: HasType<Vec<T>>,
+  OldListVal: HasType<Vec<T>>,
+> {
+  pub list: Named<NewListVal, Vec<T>>,
+  pub sorted: Sorted<T, NewListVal>,
+  pub permutation_of: PermutationOf<T, NewListVal, OldListVal>,
+}
+
+pub struct ExistDedup<T, NewListVal: HasType<Vec<T>>>
+{
+  pub list: Named<NewListVal, Vec<T>>,
+  pub sorted: Sorted<T, NewListVal>,
+  pub distinct: Distinct<T, NewListVal>,
+}
+
+pub struct ExistFound<T, IndexVal: HasType<usize>, ListVal: HasType<Vec<T>>>
+{
+  pub index: Named<IndexVal, usize>,
+  pub in_bounds: InBounds<Vec<T>, IndexVal, ListVal>,
+}
+
+pub fn check_sorted<T: Ord, ListVal: HasType<Vec<T>>>(
+  list: impl AsNamedRef<ListVal, Vec<T>>
+) -> Option<Sorted<T, ListVal>>
+{
+  let list = list.as_named_ref();
+  if list.value().windows(2).all(|pair| pair[0] <= pair[1]) {
+    Some(Sorted::new())
+  } else {
+    None
+  }
+}
+
+/**
+ Sorts a named list, returning the sorted list with a new name
+ together with the proofs that it is sorted and is a permutation
+ of the original list.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::sort::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let list = seed1.new_named(vec![3, 1, 2]);
+
+   let ExistSorted { list, sorted, .. } = sort(seed2, list);
+
+   let found = binary_search(seed3, &list, &2, &sorted)
+     .expect("2 should be found in list");
+   assert_eq!(*found.index.value(), 1);
+ });
+ ```
+*/
+pub fn sort<T: Ord, ListVal: HasType<Vec<T>>>(
+  seed: impl Seed,
+  list: Named<ListVal, Vec<T>>,
+) -> ExistSorted<T, impl HasType<Vec<T>>, ListVal>
+{
+  let mut list = list.into_value();
+  list.sort();
+
+  ExistSorted {
+    list: seed.new_named(list),
+    sorted: Sorted::new(),
+    permutation_of: PermutationOf::new(),
+  }
+}
+
+/**
+ Binary searches a sorted list for the given element. On success,
+ the index of the element is returned as a named value together
+ with the proof that it is in bounds of the list. Otherwise the
+ index where the element could be inserted is returned, the same
+ as [`slice::binary_search`]. The index is checked to be in bounds
+ before the proof is constructed, so an inconsistent [`Ord`]
+ implementation of `T` can only lead to an unspecified result.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::sort::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let list = seed1.new_named(vec![1, 3, 5]);
+   let sorted = check_sorted(&list).expect("list should be sorted");
+
+   assert_eq!(binary_search(seed2, &list, &4, &sorted).err(), Some(2));
+
+   let found = binary_search(seed3, &list, &5, &sorted)
+     .expect("5 should be found in list");
+   assert_eq!(*found.index.value(), 2);
+ });
+ ```
+*/
+pub fn binary_search<S: Seed, T: Ord, ListVal: HasType<Vec<T>>>(
+  seed: S,
+  list: impl AsNamedRef<ListVal, Vec<T>>,
+  elem: &T,
+  _sorted: &Sorted<T, ListVal>,
+) -> Result<ExistFound<T, S::Name<usize>, ListVal>, usize>
+{
+  let list = list.as_named_ref();
+  let list = list.value();
+
+  match list.binary_search(elem) {
+    // `slice::binary_search` does not specify its result for an `Ord`
+    // that is not a total order, so the index is checked before the
+    // `InBounds` proof is constructed.
+    Ok(index) if index < list.len() => Ok(ExistFound {
+      index: seed.new_named(index),
+      in_bounds: new_in_bounds(),
+    }),
+    Ok(_) => Err(list.len()),
+    Err(index) => Err(index),
+  }
+}
+
+/**
+ Removes consecutive duplicate elements from a sorted list. Since
+ the list is sorted, the resulting list has no duplicate elements
+ and remains sorted.
+
+ ```rust
+ # use mononym::*;
+ # use mononym::proof::sort::*;
+ with_seed(|life| {
+   let (seed1, seed2) = life.into_seed().replicate();
+   let (seed2, seed3) = seed2.replicate();
+   let list = seed1.new_named(vec![3, 1, 3, 2, 1]);
+
+   let ExistSorted { list, sorted, .. } = sort(seed2, list);
+   let ExistDedup { list, .. } = dedup(seed3, list, sorted);
+
+   assert_eq!(list.value(), &vec![1, 2, 3]);
+   assert!(check_sorted(&list).is_some());
+ });
+ ```
+*/
+pub fn dedup<T: Ord, ListVal: HasType<Vec<T>>>(
+  seed: impl Seed,
+  list: Named<ListVal, Vec<T>>,
+  _sorted: Sorted<T, ListVal>,
+) -> ExistDedup<T, impl HasType<Vec<T>>>
+{
+  let mut list = list.into_value();
+  list.dedup();
+
+  ExistDedup {
+    list: seed.new_named(list),
+    sorted: Sorted::new(),
+    distinct: Distinct::new(),
+  }
+}
+
+pub fn permutation_refl<T, ListVal: HasType<Vec<T>>>(
+  _list: impl AsNamedRef<ListVal, Vec<T>>
+) -> PermutationOf<T, ListVal, ListVal>
+{
+  PermutationOf::new()
+}
+
+pub fn permutation_commutative<
+  T,
+  NewListVal: HasType<Vec<T>>,
+  OldListVal: HasType<Vec<T>>,
+>(
+  _permutation_of: &PermutationOf<T, NewListVal, OldListVal>
+) -> PermutationOf<T, OldListVal, NewListVal>
+{
+  PermutationOf::new()
+}
+
+pub fn permutation_transitive<
+  T,
+  FirstListVal: HasType<Vec<T>>,
+  SecondListVal: HasType<Vec<T>>,
+  ThirdListVal: HasType<Vec<T>>,
+>(
+  _first_second: &PermutationOf<T, FirstListVal, SecondListVal>,
+  _second_third: &PermutationOf<T, SecondListVal, ThirdListVal>,
+) -> PermutationOf<T, FirstListVal, ThirdListVal>
+{
+  PermutationOf::new()
+}
+
+/**
+ A permutation has the same length as the original list, so an index
+ in bounds of the original list is also in bounds of the permutation.
+*/
+pub fn permutation_preserve_in_bounds<
+  T,
+  NewListVal: HasType<Vec<T>>,
+  OldListVal: HasType<Vec<T>>,
+  IndexVal: HasType<usize>,
+>(
+  _in_bounds: &InBounds<Vec<T>, IndexVal, OldListVal>,
+  _permutation_of: &PermutationOf<T, NewListVal, OldListVal>,
+) -> InBounds<Vec<T>, IndexVal, NewListVal>
+{
+  new_in_bounds()
+}
+
+pub fn permutation_preserve_distinct<
+  T,
+  NewListVal: HasType<Vec<T>>,
+  OldListVal: HasType<Vec<T>>,
+>(
+  _distinct: &Distinct<T, OldListVal>,
+  _permutation_of: &PermutationOf<T, NewListVal, OldListVal>,
+) -> Distinct<T, NewListVal>
+{
+  Distinct::new()
+}
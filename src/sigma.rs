@@ -0,0 +1,193 @@
+/*!
+ A library type for dependent pairs, as an alternative to the structs
+ generated by [`exists!`](crate::exists). Since all dependent pairs
+ share the same [`Exists`] type, generic combinators can be written
+ once for all of them.
+*/
+
+use crate::named::*;
+
+/**
+ A family of proof types indexed by the name of a value of type `T`.
+ The family is usually a marker type that carries the other names
+ the proof refers to, with the witness name left open. Families of
+ proofs that only depend on the named value can also implement
+ [`ValueFamily`](crate::proof::equal::ValueFamily), so that the
+ proofs can be rewritten with
+ [`transport`](crate::proof::equal::transport).
+
+ ```rust
+ # use mononym::{*, sigma::*, proof::num::*};
+ struct IsPositive;
+
+ impl ProofFamily<u32> for IsPositive
+ {
+   type Proof<N: HasType<u32>> = Positive<u32, N>;
+ }
+ ```
+*/
+pub trait ProofFamily<T: ?Sized>
+{
+  type Proof<N: HasType<T>>;
+}
+
+/**
+ A dependent pair of a named value with the name `N`, together with
+ a proof of type `F::Proof<N>` about the same name.
+
+ The pair can be destructured with its public fields, or with
+ [`Exists::into_parts`].
+
+ ```rust
+ # use mononym::{*, sigma::*, proof::num::*};
+ struct IsPositive;
+
+ impl ProofFamily<u32> for IsPositive
+ {
+   type Proof<N: HasType<u32>> = Positive<u32, N>;
+ }
+
+ struct IsNonZero;
+
+ impl ProofFamily<u32> for IsNonZero
+ {
+   type Proof<N: HasType<u32>> = NonZero<u32, N>;
+ }
+
+ fn increment(
+   seed: impl Seed,
+   num: u32,
+ ) -> Option<Exists<impl HasType<u32>, u32, IsPositive>>
+ {
+   let num = num.checked_add(1)?;
+   Some(Exists::new(seed, num, |num| check_positive(num).unwrap()))
+ }
+
+ with_seed(|life| {
+   let positive = increment(life.into_seed(), 1).unwrap();
+   let non_zero: Exists<_, _, IsNonZero> =
+     positive.map_proof(|positive| positive_non_zero(&positive));
+
+   let Exists { value, proof: _ } = non_zero;
+   assert_eq!(*value.value(), 2);
+ });
+ ```
+*/
+pub struct Exists<N: HasType<T>, T, F: ProofFamily<T>>
+{
+  pub value: Named<N, T>,
+  pub proof: F::Proof<N>,
+}
+
+impl<N: HasType<T>, T, F: ProofFamily<T>> Exists<N, T, F>
+{
+  /**
+   Gives the value a fresh name from the seed, and constructs the
+   proof about the named value with `proof_fn`.
+  */
+  pub fn new<S: Seed<Name<T> = N>>(
+    seed: S,
+    value: T,
+    proof_fn: impl FnOnce(&Named<N, T>) -> F::Proof<N>,
+  ) -> Self
+  {
+    let value = seed.new_named(value);
+    let proof = proof_fn(&value);
+    Exists { value, proof }
+  }
+
+  /**
+   Packs an existing named value with a proof about it.
+  */
+  pub fn from_parts(
+    value: Named<N, T>,
+    proof: F::Proof<N>,
+  ) -> Self
+  {
+    Exists { value, proof }
+  }
+
+  pub fn into_parts(self) -> (Named<N, T>, F::Proof<N>)
+  {
+    (self.value, self.proof)
+  }
+
+  /**
+   Turns the proof into a proof of another family about the same
+   named value.
+  */
+  pub fn map_proof<G: ProofFamily<T>>(
+    self,
+    f: impl FnOnce(F::Proof<N>) -> G::Proof<N>,
+  ) -> Exists<N, T, G>
+  {
+    Exists {
+      value: self.value,
+      proof: f(self.proof),
+    }
+  }
+
+  /**
+   Transforms the value into a new value with a fresh name from the
+   seed. Since the new value has a different name, `proof_fn` is given
+   the old proof together with the new named value to construct the
+   proof about the new name.
+  */
+  pub fn map_value<S: Seed, U, G: ProofFamily<U>>(
+    self,
+    seed: S,
+    f: impl FnOnce(T) -> U,
+    proof_fn: impl FnOnce(
+      F::Proof<N>,
+      &Named<S::Name<U>, U>,
+    ) -> G::Proof<S::Name<U>>,
+  ) -> Exists<S::Name<U>, U, G>
+  {
+    let proof = self.proof;
+    Exists::new(seed, f(self.value.into_value()), move |value| {
+      proof_fn(proof, value)
+    })
+  }
+
+  /**
+   Constructs a proof of another family about the same named value,
+   which may fail. This is useful for refining a dependent pair with
+   additional checks.
+
+   ```rust
+   # use mononym::{*, sigma::*, proof::num::*};
+   struct IsNonZero;
+
+   impl ProofFamily<u32> for IsNonZero
+   {
+     type Proof<N: HasType<u32>> = NonZero<u32, N>;
+   }
+
+   struct IsPositive;
+
+   impl ProofFamily<u32> for IsPositive
+   {
+     type Proof<N: HasType<u32>> = Positive<u32, N>;
+   }
+
+   with_seed(|life| {
+     let non_zero: Exists<_, u32, IsNonZero> =
+       Exists::new(life.into_seed(), 3, |num| check_non_zero(num).unwrap());
+
+     let positive = non_zero.and_then::<IsPositive>(|num, _| check_positive(num));
+     assert!(positive.is_some());
+   });
+   ```
+  */
+  pub fn and_then<G: ProofFamily<T>>(
+    self,
+    f: impl FnOnce(&Named<N, T>, F::Proof<N>) -> Option<G::Proof<N>>,
+  ) -> Option<Exists<N, T, G>>
+  {
+    let proof = f(&self.value, self.proof)?;
+    Some(Exists {
+      value: self.value,
+      proof,
+    })
+  }
+}